@@ -1,6 +1,168 @@
-pub trait Day {
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+
+pub trait Day: Sync {
     fn title(&self) -> &'static str;
     fn description(&self) -> &'static str;
-    fn task_1(&self) -> String;
-    fn task_2(&self) -> String;
+    fn task_1(&self, input: &str, params: &Params) -> String;
+    fn task_2(&self, input: &str, params: &Params) -> String;
+
+    /// Names of tasks that go beyond the puzzle itself (e.g. analyses of the input), which can be run like tasks 1 and 2.
+    fn extra_tasks(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn extra_task(&self, _name: &str, _input: &str, _params: &Params) -> Option<String> {
+        None
+    }
+
+    /// Whether a param (with the given value) refers to a file the day reads or writes through `Params::path`,
+    /// so that callers who may not use files (e.g. the HTTP API) can reject it before running anything.
+    fn is_file_param(&self, _key: &str, _value: &str) -> bool {
+        false
+    }
+}
+
+/// Names of all the tasks of a day: "1" and "2", followed by the day's extra tasks.
+pub fn task_names(day: &dyn Day) -> Vec<&'static str> {
+    ["1", "2"]
+        .into_iter()
+        .chain(day.extra_tasks().iter().copied())
+        .collect()
+}
+
+/// Runs a task by its name, or returns None if the day has no such task.
+pub fn run_named_task(day: &dyn Day, task: &str, input: &str, params: &Params) -> Option<String> {
+    match task {
+        "1" => Some(day.task_1(input, params)),
+        "2" => Some(day.task_2(input, params)),
+        name => day.extra_task(name, input, params),
+    }
+}
+
+/// Day-specific parameters for tasks, e.g. `--param rules=rpsls` (or just `--param lenient` for flags).
+#[derive(Clone, Debug, Default)]
+pub struct Params {
+    values: HashMap<String, String>,
+    /// Whether params may not refer to files, see `Params::path`.
+    sandboxed: bool,
+}
+
+impl Params {
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (String, String)>) -> Self {
+        Self {
+            values: pairs.into_iter().collect(),
+            sandboxed: false,
+        }
+    }
+
+    /// Rejects any param that refers to a file, for callers that shouldn't be able to read or write files
+    /// (or stdin) as the process does, e.g. over the HTTP API.
+    pub fn sandboxed(self) -> Self {
+        Self {
+            sandboxed: true,
+            ..self
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|value| value.as_str())
+    }
+
+    /// A param that refers to a file to read or write (or `-` for stdin), which sandboxed params don't allow.
+    /// Callers that sandbox params should reject these up front (see `Day::is_file_param`), so this is only a backstop.
+    pub fn path(&self, key: &str) -> Option<&str> {
+        let path = self.get(key)?;
+        if self.sandboxed {
+            panic!("param {} refers to a file, which is not allowed here", key);
+        }
+
+        Some(path)
+    }
+
+    /// Whether a flag param (e.g. `--param lenient`) was given.
+    pub fn flag(&self, key: &str) -> bool {
+        self.parse(key, false)
+    }
+
+    /// Parses a param into the given type, falling back to the default when it wasn't given.
+    pub fn parse<T: FromStr>(&self, key: &str, default: T) -> T
+    where
+        T::Err: Display,
+    {
+        match self.get(key) {
+            Some(value) => value
+                .parse()
+                .unwrap_or_else(|e| panic!("invalid value for param {}: {}: {}", key, value, e)),
+            None => default,
+        }
+    }
+}
+
+/// Parses a single `key=value` (or `key`) parameter.
+pub fn parse_param(param: &str) -> Result<(String, String), String> {
+    match param.split_once('=') {
+        Some(("", _)) => Err("param name cannot be empty".to_string()),
+        Some((key, value)) => Ok((key.to_string(), value.to_string())),
+        None if param.is_empty() => Err("param name cannot be empty".to_string()),
+        None => Ok((param.to_string(), "true".to_string())),
+    }
+}
+
+/// Strips the indentation (and surrounding blank lines) that descriptions inherit from the code they are written in.
+pub fn render_description(description: &str) -> String {
+    let lines = description
+        .lines()
+        .map(|ln| ln.trim_end())
+        .skip_while(|ln| ln.is_empty())
+        .collect_vec();
+
+    let indentation = lines
+        .iter()
+        .filter(|ln| !ln.is_empty())
+        .map(|ln| ln.len() - ln.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|ln| ln.get(indentation..).unwrap_or(""))
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+/// The result of running a task (or the message it panicked with), and how long it took.
+pub type TaskOutcome = (Result<String, String>, Duration);
+
+/// Runs one of the day's tasks and times it.
+///
+/// Tasks panic when they can't make sense of their input, so a panic is reported as an error instead.
+pub fn run_task(day: &dyn Day, task: &str, input: &str, params: &Params) -> TaskOutcome {
+    let started_at = Instant::now();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_named_task(day, task, input, params)
+            .unwrap_or_else(|| panic!("invalid task, expected one of {:?}", task_names(day)))
+    }))
+    .map_err(|panic_payload| {
+        panic_payload
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| panic_payload.downcast_ref::<&str>().map(|s| s.to_string()))
+            .unwrap_or_else(|| "the task panicked".to_string())
+    });
+
+    (result, started_at.elapsed())
 }
@@ -1,16 +1,37 @@
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-pub mod day_13;
-pub mod day_14;
-pub mod day_15;
-pub mod day_16;
-pub mod day_17_unfinished;
-pub mod day_2;
-pub mod day_3;
-pub mod day_4;
-pub mod day_5;
-pub mod day_6;
-pub mod day_7;
-pub mod day_8;
-pub mod day_9;
+use crate::day::Day;
+
+pub mod y2022;
+
+/// The most recent event year that has solutions.
+pub const LATEST_YEAR: u16 = 2022;
+
+/// All implemented days of all event years, as (year, day, solution).
+pub fn all() -> Vec<(u16, usize, &'static dyn Day)> {
+    let events = [(2022, y2022::all())];
+
+    events
+        .into_iter()
+        .flat_map(|(year, days)| {
+            days.into_iter()
+                .map(move |(day, solution)| (year, day, solution))
+        })
+        .collect()
+}
+
+/// Every event year that has at least one implemented day.
+pub fn years() -> Vec<u16> {
+    let mut years = all()
+        .into_iter()
+        .map(|(year, _, _)| year)
+        .collect::<Vec<_>>();
+    years.dedup();
+    years
+}
+
+/// Looks up a day by its event year and number in the competition.
+pub fn get(year: u16, day: usize) -> Option<&'static dyn Day> {
+    all()
+        .into_iter()
+        .find(|(y, d, _)| *y == year && *d == day)
+        .map(|(_, _, solution)| solution)
+}
@@ -1,8 +1,8 @@
 use bitmaps::Bitmap;
 use itertools::Itertools;
 
-use crate::day::Day;
-use crate::input::input_for_day;
+use crate::day::{Day, Params};
+use crate::visualize::{self, Frame};
 
 #[derive(Clone, Copy)]
 pub struct Day10 {}
@@ -29,9 +29,8 @@ impl Day for Day10 {
         "
     }
 
-    fn task_1(&self) -> String {
-        let input = input_for_day(10);
-        let program = parse_input_into_program(&input);
+    fn task_1(&self, input: &str, _params: &Params) -> String {
+        let program = parse_input_into_program(input);
 
         let mut cpu = CPU::new(program);
 
@@ -63,9 +62,8 @@ impl Day for Day10 {
         format!("the sum of signal strength is {}", sum)
     }
 
-    fn task_2(&self) -> String {
-        let input = input_for_day(10);
-        let program = parse_input_into_program(&input);
+    fn task_2(&self, input: &str, _params: &Params) -> String {
+        let program = parse_input_into_program(input);
 
         let mut cpu = CPU::new(program);
         let mut monitor = CRT::new();
@@ -73,6 +71,7 @@ impl Day for Day10 {
         for _ in 1..=240 {
             cpu.next_cycle();
             monitor.run_draw_cycle(cpu.reg);
+            visualize::emit(|| Frame::from_text(&monitor.draw_to_string()));
         }
 
         let monitor_text = monitor.draw_to_string();
@@ -181,7 +180,7 @@ struct Execution {
     cycles_left: usize,
 }
 
-fn parse_input_into_program(input: &String) -> Program {
+fn parse_input_into_program(input: &str) -> Program {
     input
         .lines()
         .map(|line| line.trim())
@@ -2,10 +2,9 @@ use std::collections::HashMap;
 use std::ops::{Add, Mul, Rem, Sub};
 
 use itertools::Itertools;
-use regex::Regex;
 
-use crate::day::Day;
-use crate::input::input_for_day;
+use crate::day::{Day, Params};
+use crate::patterns::regex;
 
 #[derive(Clone, Copy)]
 pub struct Day11 {}
@@ -37,9 +36,7 @@ impl Day for Day11 {
         "
     }
 
-    fn task_1(&self) -> String {
-        let input = input_for_day(11);
-
+    fn task_1(&self, input: &str, _params: &Params) -> String {
         let monkey_descriptions = input
             .lines()
             .chunks(7)
@@ -102,9 +99,8 @@ impl Day for Day11 {
         )
     }
 
-    fn task_2(&self) -> String {
-        let input = input_for_day(11);
-        let modular_fields = parse_modulo_fields(&input);
+    fn task_2(&self, input: &str, _params: &Params) -> String {
+        let modular_fields = parse_modulo_fields(input);
         let monkey_descriptions = input
             .lines()
             .chunks(7)
@@ -359,7 +355,7 @@ impl Set for u64 {
     }
 }
 
-fn parse_modulo_fields(input: &String) -> Vec<u8> {
+fn parse_modulo_fields(input: &str) -> Vec<u8> {
     input
         .lines()
         .filter(|line| line.contains("divisible by"))
@@ -388,10 +384,8 @@ fn parse_into_monkey_with_u64(monkey_description: &[&str; 5]) -> Monkey<u64> {
         })
         .collect_vec();
 
-    let operation_matcher = Regex::new(
-        r"^(?P<left_operand>old|\d+) (?P<operation>\+|-|\*) (?P<right_operand>old|\d+)$",
-    )
-    .unwrap();
+    let operation_matcher =
+        regex!(r"^(?P<left_operand>old|\d+) (?P<operation>\+|-|\*) (?P<right_operand>old|\d+)$");
 
     let matched_operation = monkey_description[1]
         .trim()
@@ -471,10 +465,8 @@ fn parse_into_monkey_with_modulo(
         .map(|reg_number| ModularNumber::new(reg_number, modulo_fields))
         .collect_vec();
 
-    let operation_matcher = Regex::new(
-        r"^(?P<left_operand>old|\d+) (?P<operation>\+|-|\*) (?P<right_operand>old|\d+)$",
-    )
-    .unwrap();
+    let operation_matcher =
+        regex!(r"^(?P<left_operand>old|\d+) (?P<operation>\+|-|\*) (?P<right_operand>old|\d+)$");
 
     let matched_operation = monkey_description[1]
         .trim()
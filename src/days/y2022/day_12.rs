@@ -2,8 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 
-use crate::day::Day;
-use crate::input::input_for_day;
+use crate::day::{Day, Params};
 
 #[derive(Clone, Copy)]
 pub struct Day12 {}
@@ -35,8 +34,7 @@ impl Day for Day12 {
         "
     }
 
-    fn task_1(&self) -> String {
-        let input = input_for_day(12);
+    fn task_1(&self, input: &str, _params: &Params) -> String {
         let grid = input
             .lines()
             .map(|line| line.chars().collect_vec())
@@ -47,8 +45,7 @@ impl Day for Day12 {
         format!("the shortest path to the exit is {}", distance)
     }
 
-    fn task_2(&self) -> String {
-        let input = input_for_day(12);
+    fn task_2(&self, input: &str, _params: &Params) -> String {
         let grid = input
             .lines()
             .map(|line| line.chars().collect_vec())
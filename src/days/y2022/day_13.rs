@@ -3,8 +3,7 @@ use std::cmp::Ordering;
 use itertools::Itertools;
 use json::{self, array, JsonValue};
 
-use crate::day::Day;
-use crate::input::input_for_day;
+use crate::day::{Day, Params};
 
 #[derive(Clone, Copy)]
 pub struct Day13 {}
@@ -26,9 +25,7 @@ impl Day for Day13 {
         "
     }
 
-    fn task_1(&self) -> String {
-        let input = input_for_day(13);
-
+    fn task_1(&self, input: &str, _params: &Params) -> String {
         let pairs = input
             .lines()
             .chunks(3)
@@ -55,8 +52,7 @@ impl Day for Day13 {
         )
     }
 
-    fn task_2(&self) -> String {
-        let input = input_for_day(13);
+    fn task_2(&self, input: &str, _params: &Params) -> String {
         let mut packets = input
             .lines()
             .filter(|ln| ln.len() > 0)
@@ -1,7 +1,7 @@
 use itertools::Itertools;
 
-use crate::day::Day;
-use crate::input::input_for_day;
+use crate::day::{Day, Params};
+use crate::visualize::{self, Frame};
 
 #[derive(Clone, Copy)]
 pub struct Day14 {}
@@ -35,9 +35,8 @@ impl Day for Day14 {
         "
     }
 
-    fn task_1(&self) -> String {
-        let input = input_for_day(14);
-        let mut grid = parse_input_into_grid(&input);
+    fn task_1(&self, input: &str, _params: &Params) -> String {
+        let mut grid = parse_input_into_grid(input);
 
         let height_of_the_abyss = beep_bop_find_lowest_terrain_of_scan(&grid);
 
@@ -72,6 +71,7 @@ impl Day for Day14 {
             } else {
                 grid[grain_pos.1][grain_pos.0] = Pixel::Sand;
                 rested_grains_of_sand += 1;
+                visualize::emit(|| draw_grid(&grid, height_of_the_abyss + 2));
             }
         }
 
@@ -81,9 +81,8 @@ impl Day for Day14 {
         )
     }
 
-    fn task_2(&self) -> String {
-        let input = input_for_day(14);
-        let mut grid = parse_input_into_grid(&input);
+    fn task_2(&self, input: &str, _params: &Params) -> String {
+        let mut grid = parse_input_into_grid(input);
 
         let height_of_the_endless_floor = beep_bop_find_lowest_terrain_of_scan(&grid) + 2;
 
@@ -119,6 +118,7 @@ impl Day for Day14 {
                 break;
             } else {
                 grid[grain_pos.1][grain_pos.0] = Pixel::Sand;
+                visualize::emit(|| draw_grid(&grid, height_of_the_endless_floor));
             }
         }
 
@@ -136,7 +136,7 @@ enum Pixel {
     Sand,
 }
 
-fn parse_input_into_grid(input: &String) -> Vec<Vec<Pixel>> {
+fn parse_input_into_grid(input: &str) -> Vec<Vec<Pixel>> {
     let mut grid = vec![vec![Pixel::Empty; 1000]; 1000];
 
     for line in input.lines() {
@@ -180,3 +180,40 @@ fn beep_bop_find_lowest_terrain_of_scan(grid: &Vec<Vec<Pixel>>) -> usize {
         .map(|(i, _)| i)
         .expect("could not find terrain in any depth? this really is the abyss!")
 }
+
+/// Draws the part of the grid that sand can reach: a triangle that spreads from (500, 0) down to the floor.
+fn draw_grid(grid: &[Vec<Pixel>], floor: usize) -> Frame {
+    // with a floor deeper than 499, the triangle is cut off at the left edge of the grid
+    let leftmost = 500usize.saturating_sub(floor + 1);
+    let rightmost = 500 + floor + 1;
+    let mut frame = Frame::new(rightmost - leftmost + 1, floor + 1, '.');
+
+    for (y, line) in grid.iter().take(floor).enumerate() {
+        for (x, pixel) in line.iter().enumerate().take(rightmost + 1).skip(leftmost) {
+            match pixel {
+                Pixel::Empty => {}
+                Pixel::Ground => frame.set(x - leftmost, y, '#'),
+                Pixel::Sand => frame.set(x - leftmost, y, 'o'),
+            }
+        }
+    }
+
+    frame.set(500 - leftmost, 0, '+');
+    (0..frame.width).for_each(|x| frame.set(x, floor, '~'));
+
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_floors_deeper_than_the_sand_source_is_from_the_left_edge() {
+        let grid = vec![vec![Pixel::Empty; 1200]; 700];
+
+        let frame = draw_grid(&grid, 600);
+
+        assert_eq!((frame.width, frame.height), (1102, 601));
+    }
+}
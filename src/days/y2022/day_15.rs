@@ -1,10 +1,9 @@
 use std::cmp::Ordering;
 
 use itertools::Itertools;
-use regex::Regex;
 
-use crate::day::Day;
-use crate::input::input_for_day;
+use crate::day::{Day, Params};
+use crate::patterns::regex;
 
 #[derive(Clone, Copy)]
 pub struct Day15 {}
@@ -45,10 +44,9 @@ impl Day for Day15 {
         "
     }
 
-    fn task_1(&self) -> String {
-        let input = input_for_day(15);
+    fn task_1(&self, input: &str, _params: &Params) -> String {
         let row = 2_000_000;
-        let sensors = parse_input_into_sensors(&input);
+        let sensors = parse_input_into_sensors(input);
 
         let area_covered_by_sensors = get_coverage_for_row_with_sensors(row, &sensors);
 
@@ -69,9 +67,8 @@ impl Day for Day15 {
         )
     }
 
-    fn task_2(&self) -> String {
-        let input = input_for_day(15);
-        let sensors = parse_input_into_sensors(&input);
+    fn task_2(&self, input: &str, _params: &Params) -> String {
+        let sensors = parse_input_into_sensors(input);
 
         let only_position_for_distress_beacon = (0..=4_000_000)
             .map(|row| {
@@ -189,9 +186,9 @@ impl Coverage {
 
 type CoverageRange = (i64, i64);
 
-fn parse_input_into_sensors(input: &String) -> Vec<Sensor> {
+fn parse_input_into_sensors(input: &str) -> Vec<Sensor> {
     input.lines().map(|line| {
-        let coords_matcher = Regex::new(r"^.+x=(?P<sensor_x>[^,]+), y=(?P<sensor_y>[^:]+).+x=(?P<beacon_x>[^,]+), y=(?P<beacon_y>.+)$").unwrap();
+        let coords_matcher = regex!(r"^.+x=(?P<sensor_x>[^,]+), y=(?P<sensor_y>[^:]+).+x=(?P<beacon_x>[^,]+), y=(?P<beacon_y>.+)$");
         let caps = coords_matcher.captures(line).unwrap();
 
         let sensor_x = caps.name("sensor_x").map(|s| s.as_str().parse::<i64>()).unwrap().unwrap();
@@ -1,10 +1,9 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
 use itertools::Itertools;
-use regex::Regex;
 
-use crate::day::Day;
-use crate::input::input_for_day;
+use crate::day::{Day, Params};
+use crate::patterns::regex;
 
 #[derive(Clone, Copy)]
 pub struct Day16 {}
@@ -42,9 +41,8 @@ impl Day for Day16 {
         "
     }
 
-    fn task_1(&self) -> String {
-        let input = input_for_day(16);
-        let valves = parse_input_into_valves(&input);
+    fn task_1(&self, input: &str, _params: &Params) -> String {
+        let valves = parse_input_into_valves(input);
 
         let working_valves = valves
             .clone()
@@ -64,9 +62,8 @@ impl Day for Day16 {
         )
     }
 
-    fn task_2(&self) -> String {
-        let input = input_for_day(16);
-        let valves = parse_input_into_valves(&input);
+    fn task_2(&self, input: &str, _params: &Params) -> String {
+        let valves = parse_input_into_valves(input);
 
         let working_valves = valves
             .clone()
@@ -188,11 +185,10 @@ struct Valve {
     leads_to: HashSet<String>,
 }
 
-fn parse_input_into_valves(input: &String) -> Vec<Valve> {
-    let matcher = Regex::new(
-        r"^Valve (?P<valve_id>\w\w) .+ rate=(?P<flow_rate>\d+); .+ valves? (?P<leads_to_valves>.+)$",
-    )
-    .unwrap();
+fn parse_input_into_valves(input: &str) -> Vec<Valve> {
+    let matcher = regex!(
+        r"^Valve (?P<valve_id>\w\w) .+ rate=(?P<flow_rate>\d+); .+ valves? (?P<leads_to_valves>.+)$"
+    );
 
     input
         .lines()
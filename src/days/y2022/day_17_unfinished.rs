@@ -3,8 +3,8 @@ use std::collections::{HashMap, HashSet, VecDeque};
 use bitmaps::Bitmap;
 use itertools::Itertools;
 
-use crate::day::Day;
-use crate::input::input_for_day;
+use crate::day::{Day, Params};
+use crate::visualize::{self, Frame};
 
 #[derive(Clone, Copy)]
 pub struct Day17 {}
@@ -23,9 +23,7 @@ impl Day for Day17 {
         "
     }
 
-    fn task_1(&self) -> String {
-        let input = input_for_day(17);
-
+    fn task_1(&self, input: &str, _params: &Params) -> String {
         let mut rock_formations = [
             ["####", "", "", ""],
             [".#.", "###", ".#.", ""],
@@ -85,6 +83,7 @@ impl Day for Day17 {
             top = top.min(rock.position.1);
 
             rested_rocks.push_back(rock);
+            visualize::emit(|| draw_chamber(&rested_rocks, top));
 
             if rested_rocks.len() > 200 {
                 rested_rocks.pop_front();
@@ -94,9 +93,7 @@ impl Day for Day17 {
         format!("the highest point in the stack is {}", -top)
     }
 
-    fn task_2(&self) -> String {
-        let input = input_for_day(17);
-
+    fn task_2(&self, input: &str, _params: &Params) -> String {
         let mut rock_formations = [
             ["####", "", "", ""],
             [".#.", "###", ".#.", ""],
@@ -161,6 +158,7 @@ impl Day for Day17 {
             top = top.min(rock.position.1);
 
             rested_rocks.push_back(rock);
+            visualize::emit(|| draw_chamber(&rested_rocks, top));
 
             let top_row_encoding: (usize, usize, u8) = (
                 jet_stream.peek().unwrap().0,
@@ -273,6 +271,39 @@ impl Rock {
 
 type Position = (isize, isize);
 
+/// Draws the top part of the chamber, from a few rows above the highest rock down to the floor (if it is close enough).
+fn draw_chamber(rested_rocks: &VecDeque<Rock>, top: isize) -> Frame {
+    const ROWS_TO_DRAW: isize = 40;
+    let first_row = top - 3;
+
+    let mut frame = Frame::new(9, ROWS_TO_DRAW as usize, ' ');
+
+    for row in 0..ROWS_TO_DRAW {
+        let y = first_row + row;
+
+        if y == 1 {
+            (0..9).for_each(|x| frame.set(x, row as usize, '-'));
+            frame.set(0, row as usize, '+');
+            frame.set(8, row as usize, '+');
+            break;
+        }
+
+        frame.set(0, row as usize, '|');
+        frame.set(8, row as usize, '|');
+
+        for x in 0..7 {
+            let c = if rested_rocks.iter().any(|r| r.is_within(&(x, y))) {
+                '#'
+            } else {
+                '.'
+            };
+            frame.set((x + 1) as usize, row as usize, c);
+        }
+    }
+
+    frame
+}
+
 #[derive(Clone)]
 enum Jet {
     Right,
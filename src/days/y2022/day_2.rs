@@ -0,0 +1,537 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use itertools::Itertools;
+
+use crate::day::{Day, Params};
+
+#[derive(Clone, Copy)]
+pub struct Day2 {}
+
+impl Day for Day2 {
+    fn title(&self) -> &'static str {
+        "Rock Paper Scissors"
+    }
+
+    fn description(&self) -> &'static str {
+        "
+        The solution is pretty straightforward: we introduce a \"Match\" struct that
+        handles all the relevant logic: who wins over whom, and the scoring.
+
+        From that point onwards, all that changes between the two tasks is how we interpret the input:
+        In the first, we interpret XYZ into specific hands. In the second we interpret them according to the opponent hand.
+        Both tasks share the same parser, which turns every line into a \"Round\" and rejects malformed lines
+        (lowercase letters, extra tokens, anything but a single space between the hands) along with their line number.
+
+        The rules of the game (which hands there are, who beats whom and how everything is scored) are not hard-coded,
+        but loaded from a rule set described in JSON. Regular Rock Paper Scissors is just the default rule set.
+        You can pick another one using `--param rules=<RULES>`, where RULES is either \"rps\", \"rpsls\"
+        (Rock Paper Scissors Lizard Spock), or a path to a JSON file of your own.
+
+        With n hands, the opponent plays the first n letters starting at A, and you play the last n letters ending at Z.
+
+        Since the strategy guide never tells us what your letters mean, there's also an \"analyze\" task
+        (`--task analyze`): it scores the guide under every possible mapping of your letters to hands, as well
+        as under the lose/draw/win interpretation of the second task, and reports each total along with how the
+        round scores are distributed, and which mappings score the highest and lowest.
+        "
+    }
+
+    fn task_1(&self, input: &str, params: &Params) -> String {
+        let rules = RuleSet::from_params(params);
+
+        let total_score: u32 = parse_strategy_guide(input, &rules, YourLetter::Hand)
+            .iter()
+            .map(|round| Match {
+                your_hand: rules
+                    .your_hand_from_letter(round.your_letter)
+                    .expect("letters are validated when parsing"),
+                their_hand: round.their_hand,
+            })
+            .map(|m| m.score(&rules))
+            .sum();
+
+        format!("total score: {}", total_score)
+    }
+
+    fn task_2(&self, input: &str, params: &Params) -> String {
+        let rules = RuleSet::from_params(params);
+
+        let total_score: u32 = parse_strategy_guide(input, &rules, YourLetter::Outcome)
+            .iter()
+            .map(|round| Match {
+                your_hand: round.hand_by_outcome(&rules),
+                their_hand: round.their_hand,
+            })
+            .map(|m| m.score(&rules))
+            .sum();
+
+        format!("total score: {}", total_score)
+    }
+
+    fn extra_tasks(&self) -> &'static [&'static str] {
+        &["analyze"]
+    }
+
+    fn extra_task(&self, name: &str, input: &str, params: &Params) -> Option<String> {
+        match name {
+            "analyze" => Some(analyze_strategy_guide(input, &RuleSet::from_params(params))),
+            _ => None,
+        }
+    }
+
+    fn is_file_param(&self, key: &str, value: &str) -> bool {
+        key == "rules" && built_in_rule_set(value).is_none()
+    }
+}
+
+/// Trying every mapping takes factorial time, so we only analyze rule sets up to this size.
+const MAX_HANDS_TO_ANALYZE: usize = 6;
+
+fn analyze_strategy_guide(input: &str, rules: &RuleSet) -> String {
+    if rules.hands.len() > MAX_HANDS_TO_ANALYZE {
+        panic!(
+            "can only analyze rule sets of up to {} hands",
+            MAX_HANDS_TO_ANALYZE
+        );
+    }
+
+    let first_letter = (b'Z' + 1 - rules.hands.len() as u8) as char;
+    let rounds = parse_strategy_guide(input, rules, YourLetter::Hand);
+
+    let mut report = vec![];
+
+    let mut mapping_scores = vec![];
+    for mapping in (0..rules.hands.len())
+        .map(Hand)
+        .permutations(rules.hands.len())
+    {
+        let matches = rounds.iter().map(|round| Match {
+            your_hand: mapping[round.your_letter as usize - first_letter as usize],
+            their_hand: round.their_hand,
+        });
+        let scores = ScoreSummary::of(matches, rules);
+
+        let mapping_name = mapping
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| {
+                format!(
+                    "{}={}",
+                    (first_letter as u8 + i as u8) as char,
+                    rules.hands[hand.0].name
+                )
+            })
+            .join(" ");
+
+        report.push(format!("{}: {}", mapping_name, scores));
+        mapping_scores.push((mapping_name, scores.total));
+    }
+
+    if rules.hands.len() == 3 {
+        let matches = rounds.iter().map(|round| Match {
+            your_hand: round.hand_by_outcome(rules),
+            their_hand: round.their_hand,
+        });
+        report.push(format!(
+            "X=lose Y=draw Z=win: {}",
+            ScoreSummary::of(matches, rules)
+        ));
+    }
+
+    let (highest_mapping, highest_total) = mapping_scores
+        .iter()
+        .max_by_key(|(_, total)| *total)
+        .expect("there is always at least one mapping");
+    let (lowest_mapping, lowest_total) = mapping_scores
+        .iter()
+        .min_by_key(|(_, total)| *total)
+        .expect("there is always at least one mapping");
+    report.push(format!("highest: {} ({})", highest_mapping, highest_total));
+    report.push(format!("lowest: {} ({})", lowest_mapping, lowest_total));
+
+    report.join("\n")
+}
+
+/// A single line of the strategy guide. Your letter is kept as is, since its meaning depends on the task.
+struct Round {
+    their_hand: Hand,
+    your_letter: char,
+}
+
+impl Round {
+    /// Interprets your letter as the outcome of the round (X: lose, Y: draw, Z: win), and picks the matching hand.
+    /// The letter must have been parsed as a `YourLetter::Outcome`.
+    fn hand_by_outcome(&self, rules: &RuleSet) -> Hand {
+        match self.your_letter {
+            'X' => rules.hand_beaten_by(self.their_hand),
+            'Y' => self.their_hand,
+            'Z' => rules.hand_that_beats(self.their_hand),
+            letter => panic!("expected an outcome of X, Y or Z, got {}", letter),
+        }
+    }
+}
+
+/// What your letter in the strategy guide stands for.
+#[derive(Clone, Copy)]
+enum YourLetter {
+    /// One of your hands (the last letters of the alphabet, one per hand)
+    Hand,
+    /// The outcome of the round: X, Y or Z
+    Outcome,
+}
+
+/// Parses the strategy guide, panicking with the offending line's number if it is malformed.
+fn parse_strategy_guide(input: &str, rules: &RuleSet, your_letter: YourLetter) -> Vec<Round> {
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            parse_round(line.trim_end(), rules, your_letter)
+                .unwrap_or_else(|e| panic!("invalid strategy guide, line {}: {}", i + 1, e))
+        })
+        .collect()
+}
+
+/// Parses a single line of the strategy guide, which must be exactly two uppercase letters separated by a space.
+fn parse_round(line: &str, rules: &RuleSet, your_letter: YourLetter) -> Result<Round, String> {
+    let parse_letter = |token: &str| -> Result<char, String> {
+        match token.chars().collect_vec().as_slice() {
+            [letter] if letter.is_ascii_uppercase() => Ok(*letter),
+            [letter] if letter.is_ascii_lowercase() => {
+                Err(format!("hands must be uppercase letters, got {}", letter))
+            }
+            _ => Err(format!("expected a single letter, got {:?}", token)),
+        }
+    };
+
+    let tokens = line.split(' ').collect_vec();
+    let [their_token, your_token] = tokens.as_slice() else {
+        return if line.split_whitespace().count() > 2 {
+            Err(format!("unexpected extra tokens in {:?}", line))
+        } else {
+            Err(format!(
+                "expected two hands separated by a single space, got {:?}",
+                line
+            ))
+        };
+    };
+
+    let their_letter = parse_letter(their_token)?;
+    let their_hand = rules.their_hand_from_letter(their_letter).ok_or(format!(
+        "unknown opponent hand {}, expected one of {}",
+        their_letter,
+        rules.their_letters()
+    ))?;
+
+    let letter = parse_letter(your_token)?;
+    match your_letter {
+        YourLetter::Hand if rules.your_hand_from_letter(letter).is_none() => {
+            return Err(format!(
+                "unknown hand {}, expected one of {}",
+                letter,
+                rules.your_letters()
+            ));
+        }
+        YourLetter::Outcome if !matches!(letter, 'X' | 'Y' | 'Z') => {
+            return Err(format!(
+                "unknown outcome {}, expected one of X, Y, Z",
+                letter
+            ));
+        }
+        _ => {}
+    }
+
+    Ok(Round {
+        their_hand,
+        your_letter: letter,
+    })
+}
+
+/// The total score of a strategy, and how many rounds ended with each score.
+struct ScoreSummary {
+    total: u32,
+    distribution: BTreeMap<u32, usize>,
+}
+
+impl ScoreSummary {
+    fn of(matches: impl Iterator<Item = Match>, rules: &RuleSet) -> Self {
+        let mut summary = Self {
+            total: 0,
+            distribution: BTreeMap::new(),
+        };
+
+        for m in matches {
+            let score = m.score(rules);
+            summary.total += score;
+            *summary.distribution.entry(score).or_insert(0) += 1;
+        }
+
+        summary
+    }
+}
+
+impl std::fmt::Display for ScoreSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let distribution = self
+            .distribution
+            .iter()
+            .map(|(score, rounds)| format!("{}x{}", rounds, score))
+            .join(", ");
+
+        write!(f, "total {} (rounds: {})", self.total, distribution)
+    }
+}
+
+const ROCK_PAPER_SCISSORS: &str = r#"
+{
+    "hands": [
+        { "name": "Rock", "score": 1, "beats": ["Scissors"] },
+        { "name": "Paper", "score": 2, "beats": ["Rock"] },
+        { "name": "Scissors", "score": 3, "beats": ["Paper"] }
+    ],
+    "outcome_scores": { "win": 6, "draw": 3, "loss": 0 }
+}
+"#;
+
+const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = r#"
+{
+    "hands": [
+        { "name": "Rock", "score": 1 },
+        { "name": "Paper", "score": 2 },
+        { "name": "Scissors", "score": 3 },
+        { "name": "Spock", "score": 4 },
+        { "name": "Lizard", "score": 5 }
+    ],
+    "cyclic": true,
+    "outcome_scores": { "win": 6, "draw": 3, "loss": 0 }
+}
+"#;
+
+fn built_in_rule_set(name: &str) -> Option<&'static str> {
+    match name {
+        "rps" => Some(ROCK_PAPER_SCISSORS),
+        "rpsls" => Some(ROCK_PAPER_SCISSORS_LIZARD_SPOCK),
+        _ => None,
+    }
+}
+
+/// A hand is identified by its index in the rule set.
+#[derive(PartialEq, Debug, Clone, Copy)]
+struct Hand(usize);
+
+struct HandRule {
+    name: String,
+    score: u32,
+    beats: Vec<Hand>,
+}
+
+struct RuleSet {
+    hands: Vec<HandRule>,
+    win_score: u32,
+    draw_score: u32,
+    loss_score: u32,
+}
+
+impl RuleSet {
+    fn from_params(params: &Params) -> Self {
+        let description = match built_in_rule_set(params.get("rules").unwrap_or("rps")) {
+            Some(description) => description.to_string(),
+            None => {
+                let path = params.path("rules").expect("the rule set param was given");
+                fs::read_to_string(path)
+                    .unwrap_or_else(|e| panic!("could not read rule set {}: {}", path, e))
+            }
+        };
+
+        Self::from_json(&description).unwrap_or_else(|e| panic!("invalid rule set: {}", e))
+    }
+
+    /// Builds a rule set from its JSON description.
+    ///
+    /// Every hand has a name, a score, and a list of the hands it beats. Instead of listing what every hand beats,
+    /// the rule set can be marked as "cyclic": with an odd number of hands arranged in a circle, every hand beats
+    /// the hands at odd distances before it (which is how both RPS and RPSLS work).
+    fn from_json(description: &str) -> Result<Self, String> {
+        let description = json::parse(description).map_err(|e| e.to_string())?;
+
+        let hand_descriptions = description["hands"].members().collect_vec();
+        if hand_descriptions.is_empty() {
+            return Err("a rule set must have at least one hand".to_string());
+        }
+        if hand_descriptions.len() > 13 {
+            return Err(
+                "a rule set can have at most 13 hands, as each needs two letters".to_string(),
+            );
+        }
+
+        let names = hand_descriptions
+            .iter()
+            .map(|hand| {
+                hand["name"]
+                    .as_str()
+                    .map(|name| name.to_string())
+                    .ok_or("every hand must have a name".to_string())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let Some(duplicate) = names.iter().duplicates().next() {
+            return Err(format!("hand {} is defined more than once", duplicate));
+        }
+
+        let cyclic = description["cyclic"].as_bool().unwrap_or(false);
+        if cyclic && names.len() % 2 == 0 {
+            return Err("a cyclic rule set must have an odd number of hands".to_string());
+        }
+
+        let mut hands = vec![];
+        for (i, hand) in hand_descriptions.iter().enumerate() {
+            let score = hand["score"]
+                .as_u32()
+                .ok_or(format!("hand {} must have a score", names[i]))?;
+
+            let beats = if cyclic {
+                (1..names.len())
+                    .step_by(2)
+                    .map(|distance| Hand((i + names.len() - distance) % names.len()))
+                    .collect_vec()
+            } else {
+                hand["beats"]
+                    .members()
+                    .map(|beaten| {
+                        beaten
+                            .as_str()
+                            .and_then(|beaten| names.iter().position(|name| name == beaten))
+                            .map(Hand)
+                            .ok_or(format!(
+                                "hand {} beats an unknown hand: {}",
+                                names[i], beaten
+                            ))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+
+            if beats.contains(&Hand(i)) {
+                return Err(format!("hand {} cannot beat itself", names[i]));
+            }
+
+            hands.push(HandRule {
+                name: names[i].clone(),
+                score,
+                beats,
+            });
+        }
+
+        for (i, hand) in hands.iter().enumerate() {
+            if let Some(beaten) = hand
+                .beats
+                .iter()
+                .find(|b| hands[b.0].beats.contains(&Hand(i)))
+            {
+                return Err(format!(
+                    "hands {} and {} cannot beat each other",
+                    hand.name, hands[beaten.0].name
+                ));
+            }
+        }
+
+        let outcome_score = |outcome: &str| -> Result<u32, String> {
+            description["outcome_scores"][outcome]
+                .as_u32()
+                .ok_or(format!("missing score for outcome: {}", outcome))
+        };
+
+        Ok(Self {
+            hands,
+            win_score: outcome_score("win")?,
+            draw_score: outcome_score("draw")?,
+            loss_score: outcome_score("loss")?,
+        })
+    }
+
+    fn beats(&self, hand: Hand, other: Hand) -> bool {
+        self.hands[hand.0].beats.contains(&other)
+    }
+
+    fn hand_that_beats(&self, hand: Hand) -> Hand {
+        (0..self.hands.len())
+            .map(Hand)
+            .find(|other| self.beats(*other, hand))
+            .unwrap_or_else(|| panic!("no hand beats {}", self.hands[hand.0].name))
+    }
+
+    fn hand_beaten_by(&self, hand: Hand) -> Hand {
+        *self.hands[hand.0]
+            .beats
+            .first()
+            .unwrap_or_else(|| panic!("{} does not beat any hand", self.hands[hand.0].name))
+    }
+
+    fn their_hand_from_letter(&self, letter: char) -> Option<Hand> {
+        let index = (letter as usize).checked_sub('A' as usize)?;
+        Some(Hand(index)).filter(|hand| hand.0 < self.hands.len())
+    }
+
+    fn your_hand_from_letter(&self, letter: char) -> Option<Hand> {
+        let first_letter = 'Z' as usize + 1 - self.hands.len();
+        let index = (letter as usize).checked_sub(first_letter)?;
+        Some(Hand(index)).filter(|hand| hand.0 < self.hands.len())
+    }
+
+    fn their_letters(&self) -> String {
+        ('A'..='Z').take(self.hands.len()).join(", ")
+    }
+
+    fn your_letters(&self) -> String {
+        ('A'..='Z').skip(26 - self.hands.len()).join(", ")
+    }
+}
+
+struct Match {
+    your_hand: Hand,
+    their_hand: Hand,
+}
+
+impl Match {
+    fn did_win(&self, rules: &RuleSet) -> bool {
+        rules.beats(self.your_hand, self.their_hand)
+    }
+
+    fn score(&self, rules: &RuleSet) -> u32 {
+        let hand_score = rules.hands[self.your_hand.0].score;
+
+        let draw = self.your_hand == self.their_hand;
+        let win_score = if draw {
+            rules.draw_score
+        } else if self.did_win(rules) {
+            rules.win_score
+        } else {
+            rules.loss_score
+        };
+
+        hand_score + win_score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rpsls() -> Params {
+        Params::from_pairs([("rules".to_string(), "rpsls".to_string())])
+    }
+
+    #[test]
+    fn reads_every_hand_of_the_rule_set_in_task_1() {
+        assert_eq!(Day2 {}.task_1("A V\nE Z", &rpsls()), "total score: 12");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid strategy guide, line 2: unknown outcome V, expected one of X, Y, Z"
+    )]
+    fn rejects_hands_that_are_not_outcomes_in_task_2() {
+        Day2 {}.task_2("A Y\nB V", &rpsls());
+    }
+}
@@ -0,0 +1,248 @@
+use bitmaps::Bitmap;
+use itertools::Itertools;
+
+use crate::day::{Day, Params};
+
+#[derive(Clone, Copy)]
+pub struct Day3 {}
+
+impl Day for Day3 {
+    fn title(&self) -> &'static str {
+        "Rucksack Reorganization"
+    }
+
+    fn description(&self) -> &'static str {
+        "
+        A naive solution here would be to sort every array of items and then compare them in order to find matches (or use a hash map).
+        That would take O(nlogn) due to sort \\ in case of collision in hash map.
+
+        There is, though, a constraint that makes an even more efficient solution possible, and it is hinted with the *score*!
+
+        There are only 52 possible values, so we can actually use a bitmap of size 52, where every bit stands for
+        a specific item (bits 1-26 for a-z, bits 27-52 for A-Z).
+        
+        Items must be ASCII letters, and every rucksack must hold an even number of them (as the compartments are
+        the same size), otherwise the input is rejected along with the offending line. The \"duplicates\" task
+        (`--task duplicates`) lists every item that shows up in both compartments of each rucksack.
+
+        Then all we have to do is scan the first array of items and flip the relevant bits, followed by a scan
+        of the second array for an item whose bit is ticked. That makes the algorithm run in a linear complexity! (O(n) instead of O(nlogn)).
+
+        For the second task, I used a bitmap per elf and intersected the bitmaps of every group: the bits that are still
+        turned on are the items shared by the whole group. Groups are of 3 elves by default, but you can pick
+        another size with `--param group_size=<SIZE>` (if a group shares more than one item, all of them are summed).
+        "
+    }
+
+    fn task_1(&self, input: &str, _params: &Params) -> String {
+        let rucksacks = parse_rucksacks(input);
+
+        let duplicate_items = rucksacks
+            .iter()
+            .map(get_item_that_shows_in_both_compartments_of_a_rucksack);
+
+        let sum_of_duplicate_items: u32 = duplicate_items.map(|item| item.score).sum();
+
+        format!(
+            "The sum of all duplicate items is {}",
+            sum_of_duplicate_items
+        )
+    }
+
+    fn task_2(&self, input: &str, params: &Params) -> String {
+        let group_size = params.parse("group_size", 3);
+        assert!(group_size > 0, "group size must be at least 1");
+
+        let rucksacks = parse_rucksacks(input);
+
+        let mut sum_of_shared_items = 0;
+        for group in rucksacks.chunks(group_size) {
+            if group.len() < group_size {
+                panic!(
+                    "invalid input: last group only has {} out of {} elves",
+                    group.len(),
+                    group_size
+                );
+            }
+
+            let shared_items = get_items_shared_between_rucksacks(group);
+            if shared_items.is_empty() {
+                panic!("no item shared between the rucksacks of a group was found");
+            }
+
+            sum_of_shared_items += shared_items.iter().map(|item| item.score).sum::<u32>();
+        }
+
+        format!("sum of all badges is {}", sum_of_shared_items)
+    }
+
+    fn extra_tasks(&self) -> &'static [&'static str] {
+        &["duplicates"]
+    }
+
+    fn extra_task(&self, name: &str, input: &str, _params: &Params) -> Option<String> {
+        match name {
+            "duplicates" => Some(report_duplicate_items(&parse_rucksacks(input))),
+            _ => None,
+        }
+    }
+}
+
+/// Lists every item that shows up in both compartments, for every rucksack.
+fn report_duplicate_items(rucksacks: &[Rucksack]) -> String {
+    rucksacks
+        .iter()
+        .enumerate()
+        .map(|(i, rucksack)| {
+            let duplicate_items = get_items_that_show_in_both_compartments_of_a_rucksack(rucksack);
+            let duplicate_letters = if duplicate_items.is_empty() {
+                "none".to_string()
+            } else {
+                duplicate_items.iter().map(|item| item.letter()).join(", ")
+            };
+
+            format!("rucksack {}: {}", i + 1, duplicate_letters)
+        })
+        .join("\n")
+}
+
+struct Rucksack {
+    compartment_1: Compartment,
+    compartment_2: Compartment,
+}
+
+impl Rucksack {
+    /// A bitmap of all the items in the rucksack, where every item is marked by its score.
+    fn bitmap(&self) -> Bitmap<53> {
+        self.compartment_1.bitmap() | self.compartment_2.bitmap()
+    }
+}
+
+struct Compartment {
+    items: Vec<Item>,
+}
+
+impl Compartment {
+    fn bitmap(&self) -> Bitmap<53> {
+        self.items
+            .iter()
+            .fold(Bitmap::<53>::new(), |mut bitmap, item| {
+                bitmap.set(item.score as usize, true);
+                bitmap
+            })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Item {
+    score: u32,
+}
+
+impl Item {
+    /// Items are scored by their letter: a-z are 1-26, and A-Z are 27-52.
+    fn from_char(char: char) -> Result<Self, String> {
+        let score = match char {
+            'a'..='z' => char as u32 - 'a' as u32 + 1,
+            'A'..='Z' => char as u32 - 'A' as u32 + 27,
+            _ => {
+                return Err(format!(
+                    "invalid item {:?}, items must be ASCII letters",
+                    char
+                ))
+            }
+        };
+
+        Ok(Item { score })
+    }
+
+    fn letter(&self) -> char {
+        if self.score <= 26 {
+            (b'a' + self.score as u8 - 1) as char
+        } else {
+            (b'A' + self.score as u8 - 27) as char
+        }
+    }
+}
+
+fn parse_rucksacks(input: &str) -> Vec<Rucksack> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            parse_line_into_rucksack(line)
+                .unwrap_or_else(|e| panic!("invalid input, line {}: {}", i + 1, e))
+        })
+        .collect()
+}
+
+fn parse_line_into_rucksack(line: &str) -> Result<Rucksack, String> {
+    let items = line
+        .chars()
+        .map(Item::from_char)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if items.len() % 2 != 0 {
+        return Err(format!(
+            "rucksack has an odd number of items ({}), so it can't be split into two compartments",
+            items.len()
+        ));
+    }
+
+    let item_count_in_each_compartment = items.len() / 2;
+
+    let compartment_1 = Compartment {
+        items: items[..item_count_in_each_compartment].to_vec(),
+    };
+
+    let compartment_2 = Compartment {
+        items: items[item_count_in_each_compartment..].to_vec(),
+    };
+
+    Ok(Rucksack {
+        compartment_1,
+        compartment_2,
+    })
+}
+
+fn get_item_that_shows_in_both_compartments_of_a_rucksack(rucksack: &Rucksack) -> Item {
+    let mut bitmap = Bitmap::<53>::new();
+
+    for item in &rucksack.compartment_1.items {
+        bitmap.set(item.score as usize, true);
+    }
+
+    *rucksack
+        .compartment_2
+        .items
+        .iter()
+        .find(|item| bitmap.get(item.score as usize))
+        .expect("could not find an item that shows up in both compartments")
+}
+
+fn get_items_that_show_in_both_compartments_of_a_rucksack(rucksack: &Rucksack) -> Vec<Item> {
+    let items_in_both_compartments =
+        rucksack.compartment_1.bitmap() & rucksack.compartment_2.bitmap();
+
+    items_in_both_compartments
+        .into_iter()
+        .map(|score| Item {
+            score: score as u32,
+        })
+        .collect()
+}
+
+/// Finds every item that shows up in all of the given rucksacks (in either compartment).
+fn get_items_shared_between_rucksacks(rucksacks: &[Rucksack]) -> Vec<Item> {
+    let shared_items_bitmap = rucksacks
+        .iter()
+        .map(|rucksack| rucksack.bitmap())
+        .reduce(|shared, bitmap| shared & bitmap)
+        .unwrap_or_default();
+
+    shared_items_bitmap
+        .into_iter()
+        .map(|score| Item {
+            score: score as u32,
+        })
+        .collect()
+}
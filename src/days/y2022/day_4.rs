@@ -0,0 +1,280 @@
+use itertools::Itertools;
+use std::collections::BTreeMap;
+use std::ops::RangeInclusive;
+
+use crate::day::{Day, Params};
+
+#[derive(Clone, Copy)]
+pub struct Day4 {}
+
+impl Day for Day4 {
+    fn title(&self) -> &'static str {
+        "Camp Cleanup"
+    }
+
+    fn description(&self) -> &'static str {
+        "
+        First, the math:
+        Range [a,b] contains range [c,d] if a <= c and b >= d.
+        Range [a,b] overlaps range [c,d] if c <= a <= d or a <= c <= b.
+
+        Using this math, our implementation is straightforward:
+        1. Parse the input lines into tuples of range strings
+        2. Parse each range string into rust's InclusiveRange (in my code I wrapped them in a CleaningJob struct)
+        3. Task 1: check that one range contains the other
+        4. Task 2: check that one range overlaps the other
+
+        There's also a \"coverage\" task (`--task coverage`), which forgets about the pairs and looks at all the jobs together.
+        It sweeps over the sections from left to right, adding an elf whenever a job starts and removing it after the job ends,
+        which tells us how many elves cover every stretch of sections. From that we get the sections covered by more than one elf,
+        the maximum number of elves covering the same section, and the sections within the overall span that nobody covers.
+        It also counts the pairs of elves from different lines whose jobs overlap (add `--param pairs` to list them).
+        "
+    }
+
+    fn task_1(&self, input: &str, _params: &Params) -> String {
+        let cleaning_job_pairs = input.lines().map(parse_line_into_cleaning_jobs);
+
+        let pairs_where_one_job_contains_the_other =
+            cleaning_job_pairs.filter(|(job1, job2)| job1.contains(job2) || job2.contains(job1));
+
+        format!(
+            "the count of pairs where one job contains the other is {}",
+            pairs_where_one_job_contains_the_other.count()
+        )
+    }
+
+    fn task_2(&self, input: &str, _params: &Params) -> String {
+        let cleaning_job_pairs = input.lines().map(parse_line_into_cleaning_jobs);
+
+        let pairs_where_one_job_overlaps_the_other =
+            cleaning_job_pairs.filter(|(job1, job2)| job1.overlaps(job2));
+
+        format!(
+            "the count of pairs where one job overlaps the other is {}",
+            pairs_where_one_job_overlaps_the_other.count()
+        )
+    }
+
+    fn extra_tasks(&self) -> &'static [&'static str] {
+        &["coverage"]
+    }
+
+    fn extra_task(&self, name: &str, input: &str, params: &Params) -> Option<String> {
+        match name {
+            "coverage" => Some(analyze_coverage(input, params.flag("pairs"))),
+            _ => None,
+        }
+    }
+}
+
+/// An elf is identified by the line of its pair (starting at 1) and its place in the pair (1 or 2).
+struct Elf {
+    line: usize,
+    place: usize,
+    job: CleaningJob,
+}
+
+fn analyze_coverage(input: &str, list_pairs: bool) -> String {
+    let mut elves = input
+        .lines()
+        .map(parse_line_into_cleaning_jobs)
+        .enumerate()
+        .flat_map(|(i, (job1, job2))| {
+            [
+                Elf {
+                    line: i + 1,
+                    place: 1,
+                    job: job1,
+                },
+                Elf {
+                    line: i + 1,
+                    place: 2,
+                    job: job2,
+                },
+            ]
+        })
+        .collect_vec();
+
+    let segments = coverage_segments(elves.iter().map(|elf| &elf.job));
+    let sections_in = |segments: &[&(RangeInclusive<u32>, usize)]| -> String {
+        let count: u32 = segments
+            .iter()
+            .map(|(range, _)| range.end() - range.start() + 1)
+            .sum();
+        // neighbouring segments only differ in how many elves cover them, so we merge them back together
+        let ranges = segments
+            .iter()
+            .map(|(range, _)| range.clone())
+            .coalesce(|previous, next| {
+                if previous.end() + 1 == *next.start() {
+                    Ok(*previous.start()..=*next.end())
+                } else {
+                    Err((previous, next))
+                }
+            })
+            .map(|range| format!("{}-{}", range.start(), range.end()))
+            .join(", ");
+
+        if count == 0 {
+            "0".to_string()
+        } else {
+            format!("{} ({})", count, ranges)
+        }
+    };
+
+    let mut report = vec![];
+
+    let multi_covered = segments
+        .iter()
+        .filter(|(_, elves)| *elves > 1)
+        .collect_vec();
+    report.push(format!(
+        "sections covered by more than one elf: {}",
+        sections_in(&multi_covered)
+    ));
+
+    let max_overlap = segments.iter().map(|(_, elves)| *elves).max().unwrap_or(0);
+    let most_covered = segments
+        .iter()
+        .filter(|(_, elves)| *elves == max_overlap)
+        .collect_vec();
+    report.push(format!(
+        "maximum simultaneous overlap: {} elves, over sections: {}",
+        max_overlap,
+        sections_in(&most_covered)
+    ));
+
+    let uncovered = segments
+        .iter()
+        .filter(|(_, elves)| *elves == 0)
+        .collect_vec();
+    report.push(format!(
+        "sections covered by nobody: {}",
+        sections_in(&uncovered)
+    ));
+
+    // with the elves sorted by the start of their job, every elf can only overlap with the elves that start
+    // before its job ends, so we can stop looking as soon as we reach one that doesn't
+    elves.sort_by_key(|elf| *elf.job.range.start());
+    let mut overlapping_pairs = vec![];
+    for (i, elf) in elves.iter().enumerate() {
+        for other in elves[i + 1..]
+            .iter()
+            .take_while(|other| other.job.range.start() <= elf.job.range.end())
+        {
+            if other.line != elf.line {
+                overlapping_pairs.push((elf, other));
+            }
+        }
+    }
+
+    report.push(format!(
+        "overlapping elf pairs across lines: {}",
+        overlapping_pairs.len()
+    ));
+    if list_pairs {
+        for (elf, other) in overlapping_pairs {
+            report.push(format!(
+                "  line {} elf {} ({}-{}) and line {} elf {} ({}-{})",
+                elf.line,
+                elf.place,
+                elf.job.range.start(),
+                elf.job.range.end(),
+                other.line,
+                other.place,
+                other.job.range.start(),
+                other.job.range.end()
+            ));
+        }
+    }
+
+    report.join("\n")
+}
+
+/// Splits the span of all the jobs into consecutive stretches of sections, along with how many elves cover each.
+fn coverage_segments<'a>(
+    jobs: impl Iterator<Item = &'a CleaningJob>,
+) -> Vec<(RangeInclusive<u32>, usize)> {
+    // how many elves start (or stop) covering sections at every point
+    let mut changes = BTreeMap::<u32, isize>::new();
+    for job in jobs {
+        *changes.entry(*job.range.start()).or_insert(0) += 1;
+        *changes.entry(job.range.end() + 1).or_insert(0) -= 1;
+    }
+
+    let mut covering_elves = 0;
+    changes
+        .iter()
+        .tuple_windows()
+        .map(|((section, change), (next_section, _))| {
+            covering_elves += change;
+            (*section..=next_section - 1, covering_elves as usize)
+        })
+        .collect()
+}
+
+struct CleaningJob {
+    range: RangeInclusive<u32>,
+}
+
+impl CleaningJob {
+    fn contains(&self, other_job: &Self) -> bool {
+        self.range.contains(other_job.range.start()) && self.range.contains(other_job.range.end())
+    }
+
+    fn overlaps(&self, other_job: &Self) -> bool {
+        self.range.contains(other_job.range.start()) || other_job.range.contains(self.range.start())
+    }
+}
+
+fn parse_line_into_cleaning_jobs(line: &str) -> (CleaningJob, CleaningJob) {
+    let (job_desc1, job_desc2) = line
+        .split(',')
+        .collect_tuple()
+        .expect("invalid line: did not find specification of two cleaning jobs separated by comma");
+
+    let cleaning_job1 = parse_range_string_into_cleaning_job(job_desc1);
+    let cleaning_job2 = parse_range_string_into_cleaning_job(job_desc2);
+
+    (cleaning_job1, cleaning_job2)
+}
+
+fn parse_range_string_into_cleaning_job(range_string: &str) -> CleaningJob {
+    let from = range_string
+        .split('-')
+        .nth(0)
+        .map(|s| s.parse::<u32>().unwrap())
+        .expect("could not parse beginning of cleaning job range");
+
+    let to = range_string
+        .split('-')
+        .nth(1)
+        .map(|s| s.parse::<u32>().unwrap())
+        .expect("could not parse beginning of cleaning job range");
+
+    if from > to {
+        panic!(
+            "invalid cleaning job range {}: it starts after it ends",
+            range_string
+        );
+    }
+
+    CleaningJob { range: from..=to }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_single_section_ranges() {
+        assert_eq!(parse_range_string_into_cleaning_job("3-3").range, 3..=3);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid cleaning job range 5-3: it starts after it ends")]
+    fn rejects_reversed_ranges() {
+        parse_range_string_into_cleaning_job("5-3");
+    }
+}
@@ -0,0 +1,531 @@
+use itertools::Itertools;
+
+use crate::day::{Day, Params};
+use crate::patterns::regex;
+
+#[derive(Clone, Copy)]
+pub struct Day5 {}
+
+impl Day for Day5 {
+    fn title(&self) -> &'static str {
+        "Supply Stacks"
+    }
+
+    fn description(&self) -> &'static str {
+        "
+        The task itself is, again, simple. We hold a vector of stacks, where the crates are,
+        and a set of instructions that tell us how many crates to move, and which two stacks to move them between.
+
+        In the first task, we pop each crate and push it individually, simulating a crane that lifts one crate at a time.
+        In the second, we take multiple crates at once and append them in the same order to the top of the stack.
+
+        Both are implementations of a \"Crane\" trait: all a crane decides is the order in which the crates it lifted
+        end up on the target stack. Any task can use any crane with `--param crane=<CRANE>`:
+        - 9000: the CrateMover 9000 of the first task
+        - 9001: the CrateMover 9001 of the second task
+        - limited: a crane that can only lift `--param capacity=<N>` crates at once (3 by default),
+          so it splits larger moves into several lifts
+        - alternating: lifts all the crates at once, but reverses the order of every other crate
+          (the 1st, 3rd, 5th... crates from the top swap places, while the rest stay where they were)
+
+        The parsing itself was quite the challenge, though. We have two parsing functions, the first goes over
+        the (rather graphic) representation of the stacks, and generates our vector of stacks in the process.
+        It starts from the footer that numbers the stacks, which tells us how many stacks there are and where each
+        one is drawn, and then places every crate on the stack whose number is closest to it (so there can be 10+
+        stacks, and lines can be trimmed).
+        The second function uses a regex to match the \"instruction\" lines, and constructs a MoveInstruction
+        from it.
+
+        To see what the crane is actually doing, the \"replay\" task (`--task replay`) draws the stacks after every
+        instruction, in the same layout as the input. It replays the CrateMover 9000 by default.
+
+        Instructions that can't be carried out (moving from a stack that doesn't exist, or more crates than a stack has)
+        stop the crane with an error naming the instruction. With `--param lenient`, moving more crates than there are
+        just moves all of them instead.
+        "
+    }
+
+    fn task_1(&self, input: &str, params: &Params) -> String {
+        let crane = crane_from_params(params, "9000");
+        let top_crates = move_crates_and_read_top_crates(input, crane.as_ref(), params);
+
+        format!("the password from the top crates is {:?}", top_crates)
+    }
+
+    fn task_2(&self, input: &str, params: &Params) -> String {
+        let crane = crane_from_params(params, "9001");
+        let top_crates = move_crates_and_read_top_crates(input, crane.as_ref(), params);
+
+        format!("the password from the top crates is {:?}", top_crates)
+    }
+
+    fn extra_tasks(&self) -> &'static [&'static str] {
+        &["replay"]
+    }
+
+    fn extra_task(&self, name: &str, input: &str, params: &Params) -> Option<String> {
+        match name {
+            "replay" => {
+                let crane = crane_from_params(params, "9000");
+
+                let stacks = parse_crate_stacks_from_input(input);
+                let instructions = parse_move_instructions_from_input(input);
+                let lenient = params.flag("lenient");
+
+                let mut steps = vec![format!("initially:\n{}", render_crate_stacks(&stacks))];
+                for (instruction, stacks) in
+                    instructions
+                        .iter()
+                        .zip(replay(stacks, &instructions, crane.as_ref(), lenient))
+                {
+                    match stacks {
+                        Ok(stacks) => steps.push(format!(
+                            "after {}:\n{}",
+                            instruction,
+                            render_crate_stacks(&stacks)
+                        )),
+                        Err(e) => {
+                            steps.push(format!("failed to {}: {}", instruction, e));
+                            break;
+                        }
+                    }
+                }
+
+                Some(steps.join("\n\n"))
+            }
+            _ => None,
+        }
+    }
+}
+
+type CrateStack = Vec<char>;
+
+struct MoveInstruction {
+    /// The position of the instruction in the input, starting at 1.
+    index: usize,
+    amount: u32,
+    from: usize,
+    to: usize,
+}
+
+impl std::fmt::Display for MoveInstruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "move {} from {} to {}", self.amount, self.from, self.to)
+    }
+}
+
+#[derive(Debug)]
+enum CraneError {
+    NoSuchStack {
+        instruction: usize,
+        stack: usize,
+    },
+    NotEnoughCrates {
+        instruction: usize,
+        stack: usize,
+        height: usize,
+        amount: u32,
+    },
+}
+
+impl std::fmt::Display for CraneError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CraneError::NoSuchStack { instruction, stack } => {
+                write!(f, "instruction {}: stack {} does not exist", instruction, stack)
+            }
+            CraneError::NotEnoughCrates {
+                instruction,
+                stack,
+                height,
+                amount,
+            } => write!(
+                f,
+                "instruction {}: cannot move {} crates from stack {}, which only has {} (use --param lenient to move whatever is there)",
+                instruction, amount, stack, height
+            ),
+        }
+    }
+}
+
+/// Applies the instructions one by one, yielding the state of the stacks after each of them (or the error that
+/// stopped the crane, after which there is nothing more to yield).
+fn replay<'a>(
+    mut stacks: Vec<CrateStack>,
+    instructions: &'a [MoveInstruction],
+    crane: &'a dyn Crane,
+    lenient: bool,
+) -> impl Iterator<Item = Result<Vec<CrateStack>, CraneError>> + 'a {
+    let mut failed = false;
+    instructions.iter().map_while(move |instruction| {
+        if failed {
+            return None;
+        }
+
+        let result = apply_instruction_to_stacks(crane, instruction, &mut stacks, lenient)
+            .map(|_| stacks.clone());
+        failed = result.is_err();
+        Some(result)
+    })
+}
+
+/// Checks that the instruction can be carried out, returning how many crates will actually be moved.
+///
+/// Stacks must always exist. When lenient, moving more crates than the stack has just moves all of them.
+fn crates_to_move(
+    instruction: &MoveInstruction,
+    stacks: &[CrateStack],
+    lenient: bool,
+) -> Result<usize, CraneError> {
+    for stack in [instruction.from, instruction.to] {
+        if stack == 0 || stack > stacks.len() {
+            return Err(CraneError::NoSuchStack {
+                instruction: instruction.index,
+                stack,
+            });
+        }
+    }
+
+    let height = stacks[instruction.from - 1].len();
+    if height < instruction.amount as usize && !lenient {
+        return Err(CraneError::NotEnoughCrates {
+            instruction: instruction.index,
+            stack: instruction.from,
+            height,
+            amount: instruction.amount,
+        });
+    }
+
+    Ok(height.min(instruction.amount as usize))
+}
+
+/// Draws the stacks the same way they are drawn in the input: crates bottom-up, followed by the stack numbers.
+fn render_crate_stacks(stacks: &[CrateStack]) -> String {
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+
+    let mut lines = (0..height)
+        .rev()
+        .map(|level| {
+            stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(crate_name) => format!("[{}]", crate_name),
+                    None => "   ".to_string(),
+                })
+                .join(" ")
+        })
+        .collect_vec();
+
+    lines.push(
+        (1..=stacks.len())
+            .map(|number| format!("{:^3}", number))
+            .join(" "),
+    );
+
+    lines.join("\n")
+}
+
+/// Parses the drawing of the stacks, which ends with a line numbering them.
+///
+/// The footer is what tells us how many stacks there are and where each of them is drawn, so we don't rely on
+/// the width of the lines (which may be trimmed) or on the stack numbers being a single digit.
+fn parse_crate_stacks_from_input(input: &str) -> Vec<CrateStack> {
+    let drawing = input
+        .lines()
+        .take_while(|l| !l.trim().is_empty())
+        .collect_vec();
+
+    let (footer, crate_lines) = drawing
+        .split_last()
+        .expect("invalid input: no drawing of the stacks");
+
+    let labels = regex!(r"\d+")
+        .find_iter(footer)
+        .map(|label| {
+            let center = (label.start() + label.end() - 1) as f64 / 2.0;
+            (label.as_str(), center)
+        })
+        .collect_vec();
+
+    for (i, (label, _)) in labels.iter().enumerate() {
+        if *label != (i + 1).to_string() {
+            panic!(
+                "invalid input: expected the stacks to be numbered 1 to {} in order, found {} at position {}",
+                labels.len(),
+                label,
+                i + 1
+            );
+        }
+    }
+
+    let mut stacks = vec![CrateStack::new(); labels.len()];
+
+    // we go bottom-up, so that crates are pushed in the order they are stacked
+    for line in crate_lines.iter().rev() {
+        for crate_match in regex!(r"\[(.)\]").captures_iter(line) {
+            let crate_name = crate_match[1]
+                .chars()
+                .next()
+                .expect("regex matched a crate");
+            let column = crate_match.get(1).expect("regex matched a crate").start() as f64;
+
+            // every crate belongs to the stack whose number is drawn closest to it
+            let closest_stack = labels
+                .iter()
+                .map(|(_, center)| (center - column).abs())
+                .position_min_by(|a, b| a.total_cmp(b))
+                .unwrap_or_else(|| panic!("invalid input: crate {} is not on a stack", crate_name));
+
+            stacks[closest_stack].push(crate_name);
+        }
+    }
+
+    stacks
+}
+
+fn parse_move_instructions_from_input(input: &str) -> Vec<MoveInstruction> {
+    let instruction_regex = regex!(r"move (?P<amount>\d+) from (?P<from>\d+) to (?P<to>\d+)");
+    let is_instruction_line = |l: &str| instruction_regex.is_match(l);
+
+    let instruction_lines = input.lines().skip_while(|l| !is_instruction_line(l));
+
+    instruction_lines
+        .enumerate()
+        .map(|(i, l)| {
+            let matches = instruction_regex
+                .captures(l)
+                .expect("could not match instruction line");
+
+            let amount = matches
+                .name("amount")
+                .expect("invalid instruction: no 'amount' value")
+                .as_str()
+                .parse::<u32>()
+                .unwrap();
+
+            let from = matches
+                .name("from")
+                .expect("invalid instruction: no 'from' value")
+                .as_str()
+                .parse::<usize>()
+                .unwrap();
+
+            let to = matches
+                .name("to")
+                .expect("invalid instruction: no 'to' value")
+                .as_str()
+                .parse::<usize>()
+                .unwrap();
+
+            MoveInstruction {
+                index: i + 1,
+                amount,
+                from,
+                to,
+            }
+        })
+        .collect_vec()
+}
+
+/// A crane lifts the top crates of a stack and puts them on top of another stack.
+trait Crane {
+    /// Arranges the lifted crates (given bottom to top, as they were on their stack)
+    /// in the order they end up on the target stack.
+    fn arrange(&self, crates: &mut [char]);
+}
+
+/// Moves one crate at a time, so the crates end up reversed.
+struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn arrange(&self, crates: &mut [char]) {
+        crates.reverse();
+    }
+}
+
+/// Moves all the crates at once, so they keep their order.
+struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn arrange(&self, _crates: &mut [char]) {}
+}
+
+/// Moves up to a limited number of crates at once. Each lift keeps its order, but the lifts are stacked
+/// in reverse (the top crates are lifted first, so they end up at the bottom).
+struct CapacityLimitedCrane {
+    capacity: usize,
+}
+
+impl Crane for CapacityLimitedCrane {
+    fn arrange(&self, crates: &mut [char]) {
+        let arranged = crates
+            .rchunks(self.capacity)
+            .flat_map(|lift| lift.iter().copied())
+            .collect_vec();
+
+        crates.copy_from_slice(&arranged);
+    }
+}
+
+/// Moves all the crates at once, but reverses the order of every other crate, counting from the top.
+struct AlternatingCrane;
+
+impl Crane for AlternatingCrane {
+    fn arrange(&self, crates: &mut [char]) {
+        let reversed_positions = (0..crates.len()).rev().step_by(2).collect_vec();
+
+        for (a, b) in reversed_positions
+            .iter()
+            .zip(reversed_positions.iter().rev())
+            .take(reversed_positions.len() / 2)
+        {
+            crates.swap(*a, *b);
+        }
+    }
+}
+
+fn crane_from_params(params: &Params, default_crane: &str) -> Box<dyn Crane> {
+    match params.get("crane").unwrap_or(default_crane) {
+        "9000" => Box::from(CrateMover9000),
+        "9001" => Box::from(CrateMover9001),
+        "limited" => {
+            let capacity = params.parse("capacity", 3);
+            assert!(
+                capacity > 0,
+                "a crane must be able to lift at least one crate"
+            );
+            Box::from(CapacityLimitedCrane { capacity })
+        }
+        "alternating" => Box::from(AlternatingCrane),
+        crane => panic!(
+            "unknown crane: {}, expected one of 9000, 9001, limited or alternating",
+            crane
+        ),
+    }
+}
+
+fn move_crates_and_read_top_crates(input: &str, crane: &dyn Crane, params: &Params) -> String {
+    let mut stacks = parse_crate_stacks_from_input(input);
+    let instructions = parse_move_instructions_from_input(input);
+    let lenient = params.flag("lenient");
+
+    for instruction in &instructions {
+        apply_instruction_to_stacks(crane, instruction, &mut stacks, lenient)
+            .unwrap_or_else(|e| panic!("{}", e));
+    }
+
+    stacks
+        .iter()
+        .map(|stack| stack.last().unwrap_or(&' '))
+        .join("")
+}
+
+fn apply_instruction_to_stacks(
+    crane: &dyn Crane,
+    instruction: &MoveInstruction,
+    stacks: &mut [CrateStack],
+    lenient: bool,
+) -> Result<(), CraneError> {
+    let amount = crates_to_move(instruction, stacks, lenient)?;
+
+    let from_stack = &mut stacks[instruction.from - 1];
+    let mut lifted_crates = from_stack
+        .drain((from_stack.len() - amount)..)
+        .collect_vec();
+    crane.arrange(&mut lifted_crates);
+
+    stacks[instruction.to - 1].append(&mut lifted_crates);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small xorshift generator, so the generated stacks are the same on every run.
+    struct Generator(u64);
+
+    impl Generator {
+        fn next(&mut self, below: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % below as u64) as usize
+        }
+
+        fn stacks(&mut self, amount: usize, max_height: usize) -> Vec<CrateStack> {
+            (0..amount)
+                .map(|_| {
+                    (0..self.next(max_height + 1))
+                        .map(|_| (b'A' + self.next(26) as u8) as char)
+                        .collect()
+                })
+                .collect()
+        }
+    }
+
+    fn trim_trailing_whitespace(drawing: &str) -> String {
+        drawing.lines().map(|line| line.trim_end()).join("\n")
+    }
+
+    #[test]
+    fn parsing_a_rendered_drawing_gives_back_the_stacks() {
+        let mut generator = Generator(0x5eed);
+
+        for _ in 0..500 {
+            let amount = 1 + generator.next(15);
+            let max_height = generator.next(10);
+            let stacks = generator.stacks(amount, max_height);
+
+            let drawing = render_crate_stacks(&stacks);
+
+            assert_eq!(
+                parse_crate_stacks_from_input(&drawing),
+                stacks,
+                "{}",
+                drawing
+            );
+            assert_eq!(
+                parse_crate_stacks_from_input(&trim_trailing_whitespace(&drawing)),
+                stacks,
+                "{}",
+                drawing
+            );
+        }
+    }
+
+    #[test]
+    fn parses_more_than_nine_stacks() {
+        let stacks = (0..12)
+            .map(|i| vec![(b'A' + i as u8) as char; i % 4])
+            .collect_vec();
+
+        let drawing = render_crate_stacks(&stacks);
+
+        assert_eq!(parse_crate_stacks_from_input(&drawing), stacks);
+    }
+
+    #[test]
+    fn parses_empty_stacks() {
+        let stacks = vec![vec![], vec!['A'], vec![], vec![], vec!['B', 'C'], vec![]];
+
+        let drawing = trim_trailing_whitespace(&render_crate_stacks(&stacks));
+
+        assert_eq!(parse_crate_stacks_from_input(&drawing), stacks);
+        assert_eq!(
+            parse_crate_stacks_from_input(&render_crate_stacks(&[vec![], vec![]])),
+            vec![CrateStack::new(); 2]
+        );
+    }
+
+    #[test]
+    fn parses_the_stacks_of_a_trimmed_input() {
+        let input = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1\n";
+
+        assert_eq!(
+            parse_crate_stacks_from_input(input),
+            vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]
+        );
+    }
+}
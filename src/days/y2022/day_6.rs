@@ -0,0 +1,401 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+
+use crate::day::{Day, Params};
+
+#[derive(Clone, Copy)]
+pub struct Day6 {}
+
+impl Day for Day6 {
+    fn title(&self) -> &'static str {
+        "Tuning Trouble"
+    }
+
+    fn description(&self) -> &'static str {
+        "
+        In order to decode the message, we introduce a MarkerDetector, which is fed the datastream one character at a time.
+
+        A marker is a window of characters that are all different from each other. Instead of checking every window
+        from scratch, the detector remembers where it last saw each character, and where the current run of distinct
+        characters began: whenever a character repeats inside the run, the run restarts right after its previous
+        occurrence. Once the run is as long as the window, we found a marker.
+
+        That makes every character cost O(1), and the whole search O(n), no matter how large the window is.
+        It also means we never need the whole datastream in memory, so the detector can run on any reader.
+
+        For the first question, we look for the first window of 4 distinct characters, and for the second, of 14.
+
+        The \"markers\" task (`--task markers`) looks for every marker instead of just the first, and prints where each
+        of them ends as soon as it finds it (rather than collecting them, so it runs in constant memory, however long
+        the datastream is), followed by how many there are.
+        The window size is set with `--param window=<SIZE>` (4 by default), and instead of the input file it can read
+        any other file with `--param stream=<PATH>`, or stdin with `--param stream=-`.
+
+        The datastream is decoded as UTF-8, so a character is a character even when it takes more than one byte
+        (and positions are counted in characters). To look at the raw bytes instead, use `--param unit=bytes`.
+
+        A line break (`\\n` or `\\r\\n`) at the end of the datastream is not a part of it, but line breaks anywhere else
+        are just more characters of the datastream.
+        "
+    }
+
+    fn task_1(&self, input: &str, _params: &Params) -> String {
+        let first_char_in_message =
+            find_markers(input.as_bytes(), 4, Unit::Chars, true, |_| Ok(()))
+                .expect("reading from memory does not fail")
+                .first
+                .expect("no start-of-packet marker found");
+
+        format!(
+            "there are {} characters before the first start-of-packet",
+            first_char_in_message
+        )
+    }
+
+    fn task_2(&self, input: &str, _params: &Params) -> String {
+        let first_char_in_message =
+            find_markers(input.as_bytes(), 14, Unit::Chars, true, |_| Ok(()))
+                .expect("reading from memory does not fail")
+                .first
+                .expect("no start-of-message marker found");
+
+        format!(
+            "there are {} characters before the first start-of-message",
+            first_char_in_message
+        )
+    }
+
+    fn extra_tasks(&self) -> &'static [&'static str] {
+        &["markers"]
+    }
+
+    fn extra_task(&self, name: &str, input: &str, params: &Params) -> Option<String> {
+        match name {
+            "markers" => {
+                let window_size = params.parse("window", 4);
+                let unit = Unit::from_params(params);
+
+                let mut out = BufWriter::new(io::stdout().lock());
+                let list_marker = |position| writeln!(out, "{}", position);
+
+                let markers = match params.path("stream") {
+                    None => find_markers(input.as_bytes(), window_size, unit, false, list_marker),
+                    Some("-") => {
+                        find_markers(io::stdin().lock(), window_size, unit, false, list_marker)
+                    }
+                    Some(path) => File::open(path)
+                        .and_then(|file| find_markers(file, window_size, unit, false, list_marker)),
+                }
+                .and_then(|markers| out.flush().map(|_| markers))
+                .unwrap_or_else(|e| panic!("could not read the datastream: {}", e));
+
+                let unit_name = match unit {
+                    Unit::Chars => "characters",
+                    Unit::Bytes => "bytes",
+                };
+
+                Some(match markers.count {
+                    0 => format!("found no markers of {} {}", window_size, unit_name),
+                    count => format!(
+                        "found {} markers of {} {}, listed above by how many {} were read by the end of each",
+                        count, window_size, unit_name, unit_name
+                    ),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn is_file_param(&self, key: &str, _value: &str) -> bool {
+        key == "stream"
+    }
+}
+
+/// Finds markers in a stream of items, one item at a time.
+struct MarkerDetector<T> {
+    window_size: usize,
+    last_seen: HashMap<T, usize>,
+    /// Where the current run of distinct items began.
+    run_start: usize,
+    position: usize,
+}
+
+impl<T: Hash + Eq> MarkerDetector<T> {
+    fn new(window_size: usize) -> Self {
+        assert!(window_size > 0, "window size must be at least 1");
+
+        Self {
+            window_size,
+            last_seen: HashMap::new(),
+            run_start: 0,
+            position: 0,
+        }
+    }
+
+    /// Feeds the next item, and returns whether it completes a marker.
+    fn push(&mut self, item: T) -> bool {
+        if let Some(last_position) = self.last_seen.insert(item, self.position) {
+            self.run_start = self.run_start.max(last_position + 1);
+        }
+        self.position += 1;
+
+        self.position - self.run_start >= self.window_size
+    }
+}
+
+/// What the datastream is made of, i.e. what a marker is a window of.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Unit {
+    /// Unicode characters, decoded from UTF-8
+    Chars,
+    /// Raw bytes
+    Bytes,
+}
+
+impl Unit {
+    fn from_params(params: &Params) -> Self {
+        match params.get("unit").unwrap_or("chars") {
+            "chars" => Unit::Chars,
+            "bytes" => Unit::Bytes,
+            unit => panic!("unknown unit: {}, expected chars or bytes", unit),
+        }
+    }
+}
+
+/// The markers found in a datastream, where each of them ends being how many units were read by its end.
+#[derive(Default, PartialEq, Debug)]
+struct Markers {
+    count: usize,
+    first: Option<usize>,
+    last: Option<usize>,
+}
+
+impl Markers {
+    fn record(&mut self, position: usize) {
+        self.count += 1;
+        self.first.get_or_insert(position);
+        self.last = Some(position);
+    }
+}
+
+/// Reads the datastream to its end (or its first marker), keeping track of the markers in it and passing where
+/// each of them ends to `on_marker` as soon as it is found.
+fn find_markers(
+    source: impl Read,
+    window_size: usize,
+    unit: Unit,
+    first_only: bool,
+    on_marker: impl FnMut(usize) -> io::Result<()>,
+) -> io::Result<Markers> {
+    let bytes = BufReader::new(source).bytes();
+
+    match unit {
+        Unit::Bytes => find_markers_in(bytes, window_size, first_only, on_marker),
+        Unit::Chars => find_markers_in(decode_utf8(bytes), window_size, first_only, on_marker),
+    }
+}
+
+fn find_markers_in<T: Hash + Eq + From<u8>>(
+    items: impl Iterator<Item = io::Result<T>>,
+    window_size: usize,
+    first_only: bool,
+    mut on_marker: impl FnMut(usize) -> io::Result<()>,
+) -> io::Result<Markers> {
+    let mut detector = MarkerDetector::new(window_size);
+    let mut markers = Markers::default();
+    // a line break at the end (`\n` or `\r\n`) is not a part of the datastream, so we hold it back until something
+    // follows it. That's at most a `\r` and a `\n`, so we only need to remember which of the two we hold back.
+    let mut held_back = (false, false);
+
+    for item in items {
+        let item = item?;
+        let (held_carriage_return, held_line_feed) = held_back;
+
+        if item == T::from(b'\n') && held_carriage_return && !held_line_feed {
+            held_back = (true, true);
+            continue;
+        }
+
+        let released = [
+            held_carriage_return.then(|| T::from(b'\r')),
+            held_line_feed.then(|| T::from(b'\n')),
+        ];
+        held_back = (item == T::from(b'\r'), item == T::from(b'\n'));
+        let item = (held_back == (false, false)).then_some(item);
+
+        for item in released.into_iter().chain([item]).flatten() {
+            if detector.push(item) {
+                markers.record(detector.position);
+                on_marker(detector.position)?;
+
+                if first_only {
+                    return Ok(markers);
+                }
+            }
+        }
+    }
+
+    Ok(markers)
+}
+
+/// Decodes a stream of UTF-8 bytes into characters, without needing the whole stream in memory.
+fn decode_utf8(
+    mut bytes: impl Iterator<Item = io::Result<u8>>,
+) -> impl Iterator<Item = io::Result<char>> {
+    let invalid_data = || io::Error::new(io::ErrorKind::InvalidData, "stream is not valid UTF-8");
+
+    std::iter::from_fn(move || {
+        let first_byte = match bytes.next()? {
+            Ok(byte) => byte,
+            Err(e) => return Some(Err(e)),
+        };
+
+        // the leading bits of the first byte tell how many bytes the character takes
+        let length = match first_byte.leading_ones() {
+            0 => 1,
+            2..=4 => first_byte.leading_ones() as usize,
+            _ => return Some(Err(invalid_data())),
+        };
+
+        let mut encoded = vec![first_byte];
+        for _ in 1..length {
+            match bytes.next() {
+                Some(Ok(byte)) => encoded.push(byte),
+                Some(Err(e)) => return Some(Err(e)),
+                None => return Some(Err(invalid_data())),
+            }
+        }
+
+        Some(
+            std::str::from_utf8(&encoded)
+                .ok()
+                .and_then(|decoded| decoded.chars().next())
+                .ok_or_else(invalid_data),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn markers_in(stream: &[u8], window_size: usize, unit: Unit) -> io::Result<Markers> {
+        find_markers(stream, window_size, unit, false, |_| Ok(()))
+    }
+
+    fn positions_of_markers_in(stream: &str, window_size: usize) -> Vec<usize> {
+        let mut positions = vec![];
+        find_markers(
+            stream.as_bytes(),
+            window_size,
+            Unit::Chars,
+            false,
+            |position| {
+                positions.push(position);
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        positions
+    }
+
+    #[test]
+    fn lists_every_marker() {
+        assert_eq!(positions_of_markers_in("abcabbcda", 3), vec![3, 4, 5, 8, 9]);
+        assert_eq!(positions_of_markers_in("aab", 1), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn skips_only_a_trailing_line_break() {
+        assert_eq!(positions_of_markers_in("abc\n", 4), Vec::<usize>::new());
+        assert_eq!(positions_of_markers_in("abc\r\n", 4), Vec::<usize>::new());
+        assert_eq!(positions_of_markers_in("abc\nd", 4), vec![4, 5]);
+        assert_eq!(positions_of_markers_in("ab\r\ncd\r\n", 3), vec![3, 4, 5, 6]);
+        assert_eq!(positions_of_markers_in("ab\n\n", 2), vec![2, 3]);
+        assert_eq!(positions_of_markers_in("ab\r\n\r\n", 2), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn reads_long_runs_of_line_breaks() {
+        let stream = format!("a{}b", "\r\n".repeat(100_000));
+
+        let markers = markers_in(stream.as_bytes(), 2, Unit::Bytes).unwrap();
+
+        assert_eq!(markers.count, 200_001);
+        assert_eq!(markers.last, Some(200_002));
+    }
+
+    #[test]
+    fn counts_multibyte_characters_once() {
+        let markers = markers_in("ééa".as_bytes(), 2, Unit::Chars).unwrap();
+
+        assert_eq!(markers.first, Some(3));
+        assert_eq!(markers.count, 1);
+    }
+
+    #[test]
+    fn counts_every_byte_of_multibyte_characters() {
+        // é is encoded as C3 A9, so in bytes the stream is C3 A9 C3 A9 61
+        let markers = markers_in("ééa".as_bytes(), 2, Unit::Bytes).unwrap();
+
+        assert_eq!(markers.first, Some(2));
+        assert_eq!(markers.count, 4);
+    }
+
+    #[test]
+    fn distinct_characters_do_not_need_distinct_bytes() {
+        // every Greek letter here starts with the byte CE
+        assert_eq!(
+            markers_in("αβγδ".as_bytes(), 4, Unit::Chars).unwrap().first,
+            Some(4)
+        );
+        assert_eq!(
+            markers_in("αβγδ".as_bytes(), 4, Unit::Bytes).unwrap().first,
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_utf8() {
+        for stream in [&b"ab\xffcd"[..], b"\x80abc", b"a\xc3\x41bc"] {
+            let error = markers_in(stream, 2, Unit::Chars).unwrap_err();
+
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{:?}", stream);
+        }
+    }
+
+    #[test]
+    fn rejects_truncated_utf8() {
+        for stream in [&b"abc\xc3"[..], b"abc\xe2\x82"] {
+            let error = markers_in(stream, 2, Unit::Chars).unwrap_err();
+
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{:?}", stream);
+        }
+    }
+
+    #[test]
+    fn reads_invalid_utf8_as_bytes() {
+        let markers = markers_in(b"ab\xffcd\xc3", 4, Unit::Bytes).unwrap();
+
+        assert_eq!(markers.first, Some(4));
+        assert_eq!(markers.last, Some(6));
+    }
+
+    #[test]
+    fn finds_no_markers_in_streams_shorter_than_the_window() {
+        for unit in [Unit::Chars, Unit::Bytes] {
+            assert_eq!(markers_in(b"", 4, unit).unwrap(), Markers::default());
+            assert_eq!(markers_in(b"abc", 4, unit).unwrap(), Markers::default());
+            assert_eq!(markers_in(b"abc\n", 4, unit).unwrap(), Markers::default());
+            assert_eq!(markers_in(b"abcd", 4, unit).unwrap().first, Some(4));
+        }
+
+        assert_eq!(
+            markers_in("ééé".as_bytes(), 4, Unit::Chars).unwrap(),
+            Markers::default()
+        );
+    }
+}
@@ -0,0 +1,923 @@
+use itertools::Itertools;
+use json::{object, JsonValue};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::day::{Day, Params};
+use crate::patterns::regex;
+
+#[derive(Clone, Copy)]
+pub struct Day7 {}
+
+impl Day for Day7 {
+    fn title(&self) -> &'static str {
+        "No Space Left On Device"
+    }
+
+    fn description(&self) -> &'static str {
+        "
+        That was a HARD one. Not because the puzzle itself was hard, but because rust just HATES trees.
+
+        Simply put, I wanted to build a two-way tree of the file system's directories. Given rust's approach
+        to both mutability and ownership, creating the same node and referencing it from multiple places was near
+        impossible to handle.
+
+        The second iteration was to actually use strings instead: the tree struct would hold all nodes
+        in a flat HashMap, and they would reference each other by string.
+        
+        This worked! And actually allowed me to efficiently run through the tree both for building it and for
+        scanning it, looking for directories that fulfill the size constraints.
+
+        In addition, each line of the input is translated into a well structured command or list result type.
+        The session is replayed the way a shell would: `cd` understands absolute paths and paths of several segments
+        (including `.` and `..`), listing a directory more than once doesn't count its files twice, and anything that
+        isn't `cd`, `ls` or the output of `ls` fails with the line it appeared on.
+
+        The tasks themselves do not differ so much.
+        
+        In the first, we iterate over the resulting tree, filter all dirs under 100kb, and sum.
+
+        In the second, we iterate over the resulting tree, filter all dirs that are large enough to enable us
+        to install the update, and then return the smallest one out of those. The size of the disk and the space
+        the update requires can be changed with `--param disk=<SIZE>` and `--param required=<SIZE>`.
+
+        Deleting a single directory usually frees more than we need, though. The \"plan\" task (`--task plan`) also finds
+        the set of files and directories that frees enough space while deleting as little as possible. Deleting a
+        directory is the same as deleting all of its files, so this is a subset sum problem over the files: we go over
+        them one by one, keeping a bitmap of every total we can reach so far. We only care about totals up to what
+        we need plus the largest file (the smallest total that's large enough can't be any larger), and we keep a
+        copy of the bitmap every so often, so that we can walk back and find out which files made up the total.
+        Directories whose files were all picked are then reported as a whole.
+        Every total is a multiple of the greatest common divisor of the file sizes, so we count in units of it. If the
+        bitmaps would still take too much memory (e.g. with real byte sizes), the plan is skipped.
+
+        Since the tree remembers every file (with its name), there are also a few tasks for looking around it:
+        - ls: lists the contents of a directory, given by `--param path=<PATH>` (the root by default)
+        - tree: draws the whole tree along with the size of everything (add `--param human` for du-style sizes)
+        - largest: finds the largest file
+        - find: finds the files matching `--param glob=<GLOB>`, where `*` and `?` match anything but a slash, and `**`
+          matches anything. A glob without slashes is matched against file names, otherwise against full paths.
+        - export: describes the whole tree as JSON
+        - materialize: recreates the tree under `--param target=<DIR>` (which must be empty), with sparse files of
+          the recorded sizes, so the totals can be checked with standard tools (e.g. `du -sb <DIR>`)
+        "
+    }
+
+    fn task_1(&self, input: &str, _params: &Params) -> String {
+        let dir_tree = parse_input_into_dir_tree(input);
+
+        let dirs_under_100000 = dir_tree
+            .dirs
+            .iter()
+            .map(|(_, dir)| dir)
+            .filter(|dir| dir.size.le(&100_000))
+            .collect_vec();
+
+        format!(
+            "there are {} dirs sized under 100000, with total size of {}",
+            dirs_under_100000.len(),
+            dirs_under_100000.iter().map(|dir| dir.size).sum::<u64>(),
+        )
+    }
+
+    fn task_2(&self, input: &str, params: &Params) -> String {
+        let dir_tree = parse_input_into_dir_tree(input);
+        let disk_space_to_free = disk_space_to_free(&dir_tree, params);
+
+        let (_, dir_to_delete) = dir_tree
+            .smallest_dir_to_delete(disk_space_to_free)
+            .expect("really? no dirs?");
+
+        format!("the smallest dir to delete that will yield us enough space for update has total size of {}", dir_to_delete.size)
+    }
+
+    fn extra_tasks(&self) -> &'static [&'static str] {
+        &[
+            "ls",
+            "tree",
+            "largest",
+            "find",
+            "export",
+            "materialize",
+            "plan",
+        ]
+    }
+
+    fn extra_task(&self, name: &str, input: &str, params: &Params) -> Option<String> {
+        let dir_tree = parse_input_into_dir_tree(input);
+
+        match name {
+            "ls" => {
+                let path = params.get("path").unwrap_or("/");
+                Some(
+                    dir_tree
+                        .ls(path)
+                        .unwrap_or_else(|| panic!("no such directory: {}", path)),
+                )
+            }
+            "tree" => Some(dir_tree.tree(params.flag("human"))),
+            "export" => Some(dir_tree.to_json("/").pretty(2)),
+            "plan" => Some(plan_cleanup(
+                &dir_tree,
+                disk_space_to_free(&dir_tree, params),
+            )),
+            "materialize" => {
+                let target = params
+                    .path("target")
+                    .expect("a target directory is required, e.g. --param target=/tmp/day7");
+                let (dirs_count, files_count) = dir_tree.materialize(Path::new(target));
+
+                Some(format!(
+                    "created {} directories and {} files under {}, with a total size of {} (compare with `du -sb {}`, which also counts the directories themselves)",
+                    dirs_count,
+                    files_count,
+                    target,
+                    dir_tree.get(&"/".to_string()).unwrap().size,
+                    target
+                ))
+            }
+            "largest" => {
+                let (path, file) = dir_tree.largest_file().expect("there are no files at all");
+                Some(format!(
+                    "the largest file is {}{} ({})",
+                    path, file.name, file.size
+                ))
+            }
+            "find" => {
+                let glob = params
+                    .get("glob")
+                    .expect("a glob is required, e.g. --param glob=*.txt");
+                let matching_files = dir_tree.files_matching(glob);
+
+                Some(format!(
+                    "{} files match {}:\n{}",
+                    matching_files.len(),
+                    glob,
+                    matching_files
+                        .iter()
+                        .map(|(path, file)| format!("{}{} ({})", path, file.name, file.size))
+                        .join("\n")
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn is_file_param(&self, key: &str, _value: &str) -> bool {
+        key == "target"
+    }
+}
+
+struct DirTree {
+    dirs: HashMap<String, Directory>,
+}
+
+impl DirTree {
+    fn new() -> Self {
+        Self {
+            dirs: HashMap::from([(
+                "/".to_string(),
+                Directory {
+                    size: 0,
+                    sub_dirs: vec![],
+                    files: vec![],
+                    parent: None,
+                },
+            )]),
+        }
+    }
+
+    fn get(&self, path: &String) -> Option<&Directory> {
+        self.dirs.get(path)
+    }
+
+    fn get_mut(&mut self, path: &String) -> Option<&mut Directory> {
+        self.dirs.get_mut(path)
+    }
+
+    /// Adds a directory to the tree, unless it was already added (e.g. when a directory is listed twice).
+    fn insert_dir(&mut self, dirname: String, parent: String) {
+        let path = format!("{}{}/", parent, dirname);
+        if self.dirs.contains_key(&path) {
+            return;
+        }
+
+        let dir = Directory {
+            size: 0,
+            sub_dirs: vec![],
+            files: vec![],
+            parent: Some(parent.clone()),
+        };
+
+        self.get_mut(&parent)
+            .unwrap_or_else(|| panic!("no parent directory at {}", parent))
+            .sub_dirs
+            .push(path.clone());
+
+        self.dirs.insert(path, dir);
+    }
+
+    /// Makes sure the directory and all of its ancestors are in the tree, as we can `cd` into a directory
+    /// before (or without ever) listing its parent.
+    fn ensure_dir(&mut self, path: &str) {
+        if self.dirs.contains_key(path) {
+            return;
+        }
+
+        let (parent, dirname) = path
+            .trim_end_matches('/')
+            .rsplit_once('/')
+            .expect("the root is always in the tree");
+        let parent = format!("{}/", parent);
+
+        self.ensure_dir(&parent);
+        self.insert_dir(dirname.to_string(), parent);
+    }
+
+    /// Adds a file to a directory, unless it was already added (e.g. when a directory is listed twice).
+    fn insert_file(&mut self, name: String, size: &u64, path: &String) {
+        let dir = self
+            .get_mut(path)
+            .unwrap_or_else(|| panic!("path not found: {}", path));
+        if dir.files.iter().any(|file| file.name == name) {
+            return;
+        }
+        dir.files.push(File { name, size: *size });
+
+        let mut next_path_to_traverse = Some(path.clone());
+
+        while let Some(cur_path) = next_path_to_traverse {
+            let node = self
+                .get_mut(&cur_path)
+                .unwrap_or_else(|| panic!("path not found: {}", cur_path));
+            node.size += size;
+            next_path_to_traverse = node.parent.clone();
+        }
+    }
+
+    /// Lists a directory the same way `ls` does in the input: subdirectories first, then files.
+    fn ls(&self, path: &str) -> Option<String> {
+        let path = normalize_dir_path(path);
+        let dir = self.get(&path)?;
+
+        let sub_dirs = dir
+            .sub_dirs
+            .iter()
+            .sorted()
+            .map(|sub_dir| format!("dir {}", dir_name(sub_dir)));
+        let files = dir
+            .files
+            .iter()
+            .sorted_by(|a, b| a.name.cmp(&b.name))
+            .map(|file| format!("{} {}", file.size, file.name));
+
+        Some(sub_dirs.chain(files).join("\n"))
+    }
+
+    /// Draws the tree the way the puzzle does, along with the size of every directory.
+    fn tree(&self, human_readable_sizes: bool) -> String {
+        let format_size = |size: u64| {
+            if human_readable_sizes {
+                human_readable_size(size)
+            } else {
+                size.to_string()
+            }
+        };
+
+        let mut lines = vec![];
+        let mut to_draw = vec![("/".to_string(), 0)];
+
+        while let Some((path, depth)) = to_draw.pop() {
+            let dir = self.get(&path).expect("sub dirs are always in the tree");
+            let indentation = "  ".repeat(depth);
+
+            lines.push(format!(
+                "{}- {} (dir, size={})",
+                indentation,
+                dir_name(&path),
+                format_size(dir.size)
+            ));
+
+            for file in dir.files.iter().sorted_by(|a, b| a.name.cmp(&b.name)) {
+                lines.push(format!(
+                    "{}  - {} (file, size={})",
+                    indentation,
+                    file.name,
+                    format_size(file.size)
+                ));
+            }
+
+            // pushed in reverse, so that they're popped in order
+            for sub_dir in dir.sub_dirs.iter().sorted().rev() {
+                to_draw.push((sub_dir.clone(), depth + 1));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    /// Describes a directory and everything under it as JSON, where every entry has a name, a type, and a size
+    /// (and directories have their children).
+    fn to_json(&self, path: &str) -> JsonValue {
+        let dir = self
+            .get(&path.to_string())
+            .expect("sub dirs are always in the tree");
+
+        let sub_dirs = dir
+            .sub_dirs
+            .iter()
+            .sorted()
+            .map(|sub_dir| self.to_json(sub_dir));
+        let files = dir
+            .files
+            .iter()
+            .sorted_by(|a, b| a.name.cmp(&b.name))
+            .map(|file| {
+                object! {
+                    name: file.name.as_str(),
+                    type: "file",
+                    size: file.size,
+                }
+            });
+
+        object! {
+            name: dir_name(path),
+            type: "dir",
+            size: dir.size,
+            children: JsonValue::Array(sub_dirs.chain(files).collect()),
+        }
+    }
+
+    /// Recreates the tree under the target directory, with sparse files of the recorded sizes (so they take
+    /// almost no actual space). Returns how many directories and files were created.
+    fn materialize(&self, target: &Path) -> (usize, usize) {
+        let is_empty = fs::read_dir(target)
+            .map(|mut entries| entries.next().is_none())
+            .unwrap_or(true);
+        if !is_empty {
+            panic!(
+                "target directory {} is not empty, refusing to write into it",
+                target.display()
+            );
+        }
+
+        // names come straight from the terminal session, so we make sure none of them leads outside of the target
+        let is_safe_name = |name: &str| !name.contains('/') && name != "." && name != "..";
+        for (path, dir) in &self.dirs {
+            let names = path.split('/').filter(|name| !name.is_empty());
+            if let Some(name) = names
+                .chain(dir.files.iter().map(|file| file.name.as_str()))
+                .find(|name| !is_safe_name(name))
+            {
+                panic!(
+                    "refusing to materialize an entry named {:?} in {}",
+                    name, path
+                );
+            }
+        }
+
+        let mut files_count = 0;
+        for (path, dir) in &self.dirs {
+            let dir_path = target.join(path.trim_start_matches('/'));
+            fs::create_dir_all(&dir_path)
+                .unwrap_or_else(|e| panic!("could not create {}: {}", dir_path.display(), e));
+
+            for file in &dir.files {
+                let file_path = dir_path.join(&file.name);
+                fs::File::create(&file_path)
+                    .and_then(|created| created.set_len(file.size))
+                    .unwrap_or_else(|e| panic!("could not create {}: {}", file_path.display(), e));
+                files_count += 1;
+            }
+        }
+
+        (self.dirs.len(), files_count)
+    }
+
+    fn smallest_dir_to_delete(&self, disk_space_to_free: u64) -> Option<(&String, &Directory)> {
+        self.dirs
+            .iter()
+            .filter(|(_, dir)| dir.size >= disk_space_to_free)
+            .min_by(|(_, a), (_, b)| a.size.cmp(&b.size))
+    }
+
+    /// Every file in the tree, along with the path of its directory.
+    fn files(&self) -> impl Iterator<Item = (&String, &File)> {
+        self.dirs
+            .iter()
+            .flat_map(|(path, dir)| dir.files.iter().map(move |file| (path, file)))
+    }
+
+    fn largest_file(&self) -> Option<(&String, &File)> {
+        self.files().max_by_key(|(_, file)| file.size)
+    }
+
+    fn files_matching(&self, glob: &str) -> Vec<(&String, &File)> {
+        let pattern = glob_to_regex(glob);
+        let match_full_path = glob.contains('/');
+
+        self.files()
+            .filter(|(path, file)| {
+                if match_full_path {
+                    pattern.is_match(&format!("{}{}", path, file.name))
+                } else {
+                    pattern.is_match(&file.name)
+                }
+            })
+            .sorted_by(|(path_a, a), (path_b, b)| (path_a, &a.name).cmp(&(path_b, &b.name)))
+            .collect()
+    }
+}
+
+struct Directory {
+    size: u64,
+    sub_dirs: Vec<String>,
+    files: Vec<File>,
+    parent: Option<String>,
+}
+
+struct File {
+    name: String,
+    size: u64,
+}
+
+fn disk_space_to_free(dir_tree: &DirTree, params: &Params) -> u64 {
+    let total_disk_size = params.parse("disk", 70_000_000);
+    let required_disk_size = params.parse("required", 30_000_000);
+    assert!(
+        required_disk_size <= total_disk_size,
+        "the update requires more space than there is on the disk"
+    );
+
+    let total_taken_size = dir_tree.get(&"/".to_string()).unwrap().size;
+    total_taken_size.saturating_sub(total_disk_size - required_disk_size)
+}
+
+/// How many files we go over between copies of the bitmap of reachable totals.
+const FILES_PER_CHECKPOINT: usize = 32;
+
+/// How much memory all the copies of the bitmap of reachable totals may take together.
+const MAX_PLAN_MEMORY: u64 = 256 * 1024 * 1024;
+
+/// Reports the smallest directory that frees enough space, and the smallest set of files and directories that does.
+fn plan_cleanup(dir_tree: &DirTree, disk_space_to_free: u64) -> String {
+    let mut report = vec![format!("need to free {}", disk_space_to_free)];
+    if disk_space_to_free == 0 {
+        report.push("there is already enough space, nothing to delete".to_string());
+        return report.join("\n");
+    }
+
+    let (dir_path, dir) = dir_tree
+        .smallest_dir_to_delete(disk_space_to_free)
+        .expect("the root always frees everything");
+    report.push(format!(
+        "single directory: {} frees {} ({} more than needed)",
+        dir_path,
+        dir.size,
+        dir.size - disk_space_to_free
+    ));
+
+    let files = dir_tree
+        .files()
+        .filter(|(_, file)| file.size > 0)
+        .sorted_by(|(path_a, a), (path_b, b)| (path_a, &a.name).cmp(&(path_b, &b.name)))
+        .collect_vec();
+    let largest_file = files.iter().map(|(_, file)| file.size).max().unwrap_or(0);
+
+    // every total is a multiple of this, so the bitmap only needs a bit for every multiple
+    let unit = files.iter().fold(0, |unit, (_, file)| {
+        greatest_common_divisor(unit, file.size)
+    });
+    let needed = disk_space_to_free.div_ceil(unit);
+    let limit = needed + largest_file / unit;
+
+    // the checkpoints, the copies for walking back over a block of files, and the bitmap we fill in
+    let bitmaps = files.len().div_ceil(FILES_PER_CHECKPOINT) + FILES_PER_CHECKPOINT + 1;
+    let memory = (limit / 64 + 1) * 8 * bitmaps as u64;
+    if memory > MAX_PLAN_MEMORY {
+        report.push(format!(
+            "multiple entries: not planned, as it would take {} of memory (at most {} is allowed)",
+            human_readable_size(memory),
+            human_readable_size(MAX_PLAN_MEMORY)
+        ));
+        return report.join("\n");
+    }
+    // a bitmap that fits in memory has no more bits than fit in a usize
+    let (needed, limit) = (needed as usize, limit as usize);
+    let units_of = |file: &File| (file.size / unit) as usize;
+
+    // checkpoints[i] holds the totals reachable with the first i * FILES_PER_CHECKPOINT files
+    let mut checkpoints = vec![];
+    let mut reachable = ReachableTotals::new(limit);
+    for (i, (_, file)) in files.iter().enumerate() {
+        if i % FILES_PER_CHECKPOINT == 0 {
+            checkpoints.push(reachable.clone());
+        }
+        reachable.add(units_of(file));
+    }
+
+    let mut total = (needed..=limit)
+        .find(|total| reachable.contains(*total))
+        .expect("deleting all files always frees enough");
+    let freed = total as u64 * unit;
+
+    // walking back over the files, a file is part of the total if the total can't be reached without it
+    let mut picked = vec![false; files.len()];
+    for (block, checkpoint) in checkpoints.iter().enumerate().rev() {
+        let block_files = &files[block * FILES_PER_CHECKPOINT..]
+            [..FILES_PER_CHECKPOINT.min(files.len() - block * FILES_PER_CHECKPOINT)];
+
+        let mut reachable_before = vec![checkpoint.clone()];
+        for (_, file) in &block_files[..block_files.len() - 1] {
+            let mut next = reachable_before.last().unwrap().clone();
+            next.add(units_of(file));
+            reachable_before.push(next);
+        }
+
+        for (i, (_, file)) in block_files.iter().enumerate().rev() {
+            if !reachable_before[i].contains(total) {
+                picked[block * FILES_PER_CHECKPOINT + i] = true;
+                total -= units_of(file);
+            }
+        }
+    }
+
+    assert_eq!(total, 0, "the picked files should add up to the total");
+
+    let picked_files = files
+        .iter()
+        .zip(picked)
+        .filter(|(_, picked)| *picked)
+        .map(|((path, file), _)| (path.as_str(), file.name.as_str()))
+        .collect_vec();
+    let (entries, _) = group_into_dirs(dir_tree, "/", &picked_files);
+
+    report.push(format!(
+        "multiple entries: {} entries free {} ({} more than needed):",
+        entries.len(),
+        freed,
+        freed - disk_space_to_free
+    ));
+    report.extend(entries.iter().map(|entry| format!("  {}", entry)));
+
+    report.join("\n")
+}
+
+/// Lists the picked files under a directory, replacing any directory whose files were all picked with the
+/// directory itself. Also returns whether all the files under the directory were picked.
+fn group_into_dirs(
+    dir_tree: &DirTree,
+    path: &str,
+    picked_files: &[(&str, &str)],
+) -> (Vec<String>, bool) {
+    let dir = dir_tree
+        .get(&path.to_string())
+        .expect("sub dirs are always in the tree");
+
+    let mut entries = vec![];
+    let mut fully_picked = true;
+
+    for file in dir.files.iter().filter(|file| file.size > 0) {
+        if picked_files.contains(&(path, file.name.as_str())) {
+            entries.push(format!("{}{} ({})", path, file.name, file.size));
+        } else {
+            fully_picked = false;
+        }
+    }
+
+    for sub_dir in dir.sub_dirs.iter().sorted() {
+        let (sub_dir_entries, sub_dir_fully_picked) =
+            group_into_dirs(dir_tree, sub_dir, picked_files);
+        entries.extend(sub_dir_entries);
+        fully_picked &= sub_dir_fully_picked;
+    }
+
+    if fully_picked && dir.size > 0 {
+        (vec![format!("{} ({})", path, dir.size)], true)
+    } else {
+        (entries, fully_picked)
+    }
+}
+
+fn greatest_common_divisor(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
+
+/// A bitmap of the totals (up to a limit) that a subset of the files adds up to.
+#[derive(Clone)]
+struct ReachableTotals {
+    limit: usize,
+    words: Vec<u64>,
+}
+
+impl ReachableTotals {
+    /// Only the empty total (0) is reachable without any files.
+    fn new(limit: usize) -> Self {
+        let mut words = vec![0; limit / 64 + 1];
+        words[0] = 1;
+
+        Self { limit, words }
+    }
+
+    fn contains(&self, total: usize) -> bool {
+        total <= self.limit && self.words[total / 64] & (1 << (total % 64)) != 0
+    }
+
+    /// Adds another file: every total we had stays reachable, and so does every total plus the file.
+    fn add(&mut self, size: usize) {
+        let (word_shift, bit_shift) = (size / 64, size % 64);
+
+        // going from the top down, we only ever read words that were not updated yet
+        for i in (word_shift..self.words.len()).rev() {
+            let source = i - word_shift;
+            let mut shifted = self.words[source] << bit_shift;
+            if bit_shift > 0 && source > 0 {
+                shifted |= self.words[source - 1] >> (64 - bit_shift);
+            }
+            self.words[i] |= shifted;
+        }
+    }
+}
+
+/// Directories are keyed by their path with a trailing slash (e.g. `/a/e/`), which is easy to forget when asking for one.
+fn normalize_dir_path(path: &str) -> String {
+    if path.ends_with('/') {
+        path.to_string()
+    } else {
+        format!("{}/", path)
+    }
+}
+
+fn dir_name(path: &str) -> &str {
+    match path.trim_end_matches('/').rsplit_once('/') {
+        Some((_, name)) => name,
+        None => "/",
+    }
+}
+
+/// Formats a size the way `du -h` does, e.g. 1.5K or 48M.
+fn human_readable_size(size: u64) -> String {
+    let units = ["K", "M", "G", "T"];
+
+    let mut scaled = size as f64;
+    let mut unit = "";
+    for next_unit in units {
+        if scaled < 1024.0 {
+            break;
+        }
+        scaled /= 1024.0;
+        unit = next_unit;
+    }
+
+    if unit.is_empty() || scaled >= 10.0 {
+        format!("{}{}", scaled.ceil(), unit)
+    } else {
+        format!("{:.1}{}", (scaled * 10.0).ceil() / 10.0, unit)
+    }
+}
+
+fn glob_to_regex(glob: &str) -> Regex {
+    let mut pattern = "^".to_string();
+    let mut chars = glob.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                pattern.push_str(".*");
+            }
+            '*' => pattern.push_str("[^/]*"),
+            '?' => pattern.push_str("[^/]"),
+            c => pattern.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    pattern.push('$');
+
+    Regex::new(&pattern).unwrap_or_else(|e| panic!("invalid glob {}: {}", glob, e))
+}
+
+#[derive(Debug)]
+enum Commands {
+    CD(String),
+    LS,
+}
+
+impl Commands {
+    fn from(command: &str) -> Result<Self, String> {
+        if command.trim_end() == "$ ls" {
+            return Ok(Commands::LS);
+        }
+
+        let cd_matcher = regex!(r"^\$ cd (?P<dirname>.+)$");
+        if let Some(matched) = cd_matcher.captures(command.trim_end()) {
+            let dirname = matched.name("dirname").unwrap().as_str().to_string();
+            return Ok(Commands::CD(dirname));
+        }
+
+        Err(format!(
+            "unknown command: {} (only cd and ls are supported)",
+            command
+        ))
+    }
+}
+
+enum ListResults {
+    Dir(String),
+    File(String, u64),
+}
+
+impl ListResults {
+    fn from(list_result: &str) -> Result<Self, String> {
+        let dir_matcher = regex!(r"^dir (?P<dirname>.+)$");
+        if let Some(matched) = dir_matcher.captures(list_result) {
+            return Ok(Self::Dir(
+                matched.name("dirname").unwrap().as_str().to_string(),
+            ));
+        }
+
+        let file_matcher = regex!(r"^(?P<size>\d+) (?P<filename>.+)$");
+        if let Some(matched) = file_matcher.captures(list_result) {
+            let size = matched
+                .name("size")
+                .unwrap()
+                .as_str()
+                .parse::<u64>()
+                .map_err(|e| format!("invalid file size: {}", e))?;
+            let filename = matched.name("filename").unwrap().as_str().to_string();
+            return Ok(Self::File(filename, size));
+        }
+
+        Err(format!("unknown list result: {}", list_result))
+    }
+}
+
+/// Resolves the argument of `cd` against the current directory, the way a shell does:
+/// absolute paths start from the root, `..` goes up (but never above the root), and `.` stays put.
+fn resolve_path(current_path: &str, to: &str) -> String {
+    let mut segments = if to.starts_with('/') {
+        vec![]
+    } else {
+        current_path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect_vec()
+    };
+
+    for segment in to.split('/').filter(|segment| !segment.is_empty()) {
+        match segment {
+            "." => {}
+            ".." => {
+                segments.pop();
+            }
+            name => segments.push(name),
+        }
+    }
+
+    segments.iter().fold("/".to_string(), |path, segment| {
+        format!("{}{}/", path, segment)
+    })
+}
+
+fn parse_input_into_dir_tree(input: &str) -> DirTree {
+    let mut dir_tree = DirTree::new();
+    let mut current_path = "/".to_string();
+    let mut listing = false;
+
+    for (i, line) in input.lines().enumerate() {
+        let fail = |message: String| -> ! {
+            panic!("invalid terminal session, line {}: {}", i + 1, message)
+        };
+
+        if line.starts_with('$') {
+            listing = false;
+
+            match Commands::from(line).unwrap_or_else(|e| fail(e)) {
+                Commands::CD(to) => {
+                    current_path = resolve_path(&current_path, &to);
+                    dir_tree.ensure_dir(&current_path);
+                }
+                Commands::LS => listing = true,
+            }
+        } else if listing {
+            match ListResults::from(line).unwrap_or_else(|e| fail(e)) {
+                ListResults::File(name, size) => dir_tree.insert_file(name, &size, &current_path),
+                ListResults::Dir(name) => dir_tree.insert_dir(name, current_path.clone()),
+            }
+        } else {
+            fail(format!("output without a command: {}", line));
+        }
+    }
+
+    dir_tree
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn size_of(dir_tree: &DirTree, path: &str) -> u64 {
+        dir_tree
+            .get(&path.to_string())
+            .unwrap_or_else(|| panic!("no directory at {}", path))
+            .size
+    }
+
+    #[test]
+    fn listing_a_directory_twice_does_not_count_its_files_twice() {
+        let dir_tree = parse_input_into_dir_tree(
+            "$ cd /\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\n50 c.txt\n$ cd ..\n$ ls\ndir a\n100 b.txt\n$ cd a\n$ ls\n50 c.txt",
+        );
+
+        assert_eq!(size_of(&dir_tree, "/"), 150);
+        assert_eq!(size_of(&dir_tree, "/a/"), 50);
+        assert_eq!(dir_tree.get(&"/".to_string()).unwrap().sub_dirs.len(), 1);
+        assert_eq!(dir_tree.files().count(), 2);
+    }
+
+    #[test]
+    fn resolves_relative_paths() {
+        assert_eq!(resolve_path("/", "a/b"), "/a/b/");
+        assert_eq!(resolve_path("/x/", "a/b/"), "/x/a/b/");
+        assert_eq!(resolve_path("/x/", "a/../b"), "/x/b/");
+    }
+
+    #[test]
+    fn resolves_absolute_paths() {
+        assert_eq!(resolve_path("/a/b/", "/x/y"), "/x/y/");
+        assert_eq!(resolve_path("/a/", "/"), "/");
+    }
+
+    #[test]
+    fn resolves_the_current_directory() {
+        assert_eq!(resolve_path("/a/", "."), "/a/");
+        assert_eq!(resolve_path("/", "."), "/");
+    }
+
+    #[test]
+    fn never_goes_above_the_root() {
+        assert_eq!(resolve_path("/", ".."), "/");
+        assert_eq!(resolve_path("/a/", "../.."), "/");
+    }
+
+    #[test]
+    fn changing_into_a_nested_path_creates_its_ancestors() {
+        let dir_tree =
+            parse_input_into_dir_tree("$ cd /x/y\n$ ls\n10 f\n$ cd .\n$ cd a/b\n$ ls\n5 g");
+
+        assert_eq!(size_of(&dir_tree, "/"), 15);
+        assert_eq!(size_of(&dir_tree, "/x/"), 15);
+        assert_eq!(size_of(&dir_tree, "/x/y/"), 15);
+        assert_eq!(size_of(&dir_tree, "/x/y/a/b/"), 5);
+    }
+
+    #[test]
+    fn plans_cleanups_of_large_files_with_a_common_divisor() {
+        let dir_tree = parse_input_into_dir_tree(
+            "$ cd /\n$ ls\ndir a\n40000000000 big.iso\n12000000000 small\n$ cd a\n$ ls\n8000000000 c",
+        );
+
+        let plan = plan_cleanup(&dir_tree, 50_000_000_000);
+
+        assert!(plan.contains("2 entries free 52000000000"), "{}", plan);
+    }
+
+    #[test]
+    fn skips_plans_that_take_too_much_memory() {
+        let dir_tree = parse_input_into_dir_tree("$ cd /\n$ ls\n40000000000 big.iso\n12 small");
+
+        let plan = plan_cleanup(&dir_tree, 30_000_000_012);
+
+        assert!(
+            plan.contains("single directory: / frees 40000000012"),
+            "{}",
+            plan
+        );
+        assert!(plan.contains("not planned"), "{}", plan);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid terminal session, line 3: unknown command: $ rm -rf a")]
+    fn rejects_unknown_commands() {
+        parse_input_into_dir_tree("$ cd /\n$ ls\n$ rm -rf a");
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "invalid terminal session, line 2: output without a command: 100 a.txt"
+    )]
+    fn rejects_output_without_ls() {
+        parse_input_into_dir_tree("$ cd /\n100 a.txt");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid terminal session, line 3: output without a command: dir b")]
+    fn rejects_output_after_cd() {
+        parse_input_into_dir_tree("$ ls\n$ cd /\ndir b");
+    }
+}
@@ -0,0 +1,444 @@
+use itertools::Itertools;
+use std::fs;
+use std::io::Write;
+
+use crate::day::{Day, Params};
+
+#[derive(Clone, Copy)]
+pub struct Day8 {}
+
+impl Day for Day8 {
+    fn title(&self) -> &'static str {
+        "Treetop Tree House"
+    }
+
+    fn description(&self) -> &'static str {
+        "
+        First, we parse. We turn the grid into a heightmap: a single flat list of heights, row after row, along with the
+        width and height of the grid. To walk along a line of trees in any direction, we only need to know where the line
+        starts and how far apart its trees are in that list - 1 for a row, the width of the grid for a column, and the
+        negative of these to walk the other way. Every task is then just a few sweeps over these lines.
+
+        For the first task, we need to identify the trees visible from outside. In order to achieve that, for each tree,
+        we identify the highest tree it can see from every side.
+        
+        Let's say we have N trees in a line. We start from the first tree - there is no highest tree next to it so its value is -1.
+        The next tree only has one tree in front of it, so that tree is the highest it sees.
+        The third tree looks at the second one: if the second one is shorter than the first one, it takes the first one as the highest.
+        Otherwise, it takes the second.
+
+        Generally speaking, the highest tree that comes before any n<N tree, is either the highest tree that comes before the n-1 tree,
+        or the n-1 tree itself - the highest of the two.
+
+        In order to translate that into what we need, we sweep over every line from each of the four sides, and keep the
+        highest tree we have passed so far. Every tree that is higher than that is visible from the side we came from,
+        and we mark it as such. We don't need to remember the highest trees themselves, only which sides each tree is
+        visible from - four bits per tree.
+
+        Finally, the trees that are visible from at least one side are the visible trees, and the count of those is the answer for task 1.
+
+        As for task 2, there is an efficient O(n) solution, where we keep the distance from every possible height for each tree.
+        Since the height is 0-9, the complexity for storing all possible height is O(1).
+
+        We sweep over every row and column from both sides, remembering where we last saw a tree of each height.
+        A tree's viewing distance in the direction we came from is the distance to the closest tree we saw that is at least
+        as tall as it is (or to the edge, if there's none), which only takes looking at the 10 heights.
+        Then the scenic score of every tree is just the product of its four viewing distances.
+
+        To see all of it at once, there are two more tasks:
+        - heatmap: draws the forest in color, shading either how scenic every tree is (`--param shade=scenic`, the default)
+          or whether it is visible from outside (`--param shade=visibility`). With `--param image=<PATH>`, the map is
+          saved as a PPM image instead of being printed.
+        - csv: exports every tree's position, height, the directions it is visible from, its viewing distances and its
+          scenic score. With `--param out=<PATH>` it is saved to a file instead of being printed.
+        "
+    }
+
+    fn task_1(&self, input: &str, _params: &Params) -> String {
+        let heightmap = Heightmap::parse(input);
+
+        let visibility = heightmap.visibility();
+        let visible_trees = visibility.iter().filter(|sides| **sides != 0);
+
+        format!(
+            "count of trees visible from the outside is {}",
+            visible_trees.count()
+        )
+    }
+
+    fn task_2(&self, input: &str, _params: &Params) -> String {
+        let heightmap = Heightmap::parse(input);
+
+        let scenic_scores = heightmap.scenic_scores();
+
+        let (best_tree, best_score) = scenic_scores
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, score)| **score)
+            .expect("for some reason, no tree was hidden?");
+
+        format!(
+            "the highest score for a hidden tree is {}, for the tree at {:?}",
+            best_score,
+            heightmap.position(best_tree)
+        )
+    }
+
+    fn extra_tasks(&self) -> &'static [&'static str] {
+        &["heatmap", "csv"]
+    }
+
+    fn extra_task(&self, name: &str, input: &str, params: &Params) -> Option<String> {
+        let heightmap = Heightmap::parse(input);
+
+        match name {
+            "heatmap" => {
+                let shade = match params.get("shade").unwrap_or("scenic") {
+                    "scenic" => Shade::Scenic,
+                    "visibility" => Shade::Visibility,
+                    shade => panic!("unknown shade: {}, expected scenic or visibility", shade),
+                };
+                let colors = shade.colors(&heightmap);
+
+                match params.path("image") {
+                    Some(path) => {
+                        write_heatmap_image(&heightmap, &colors, path);
+                        Some(format!("heatmap saved to {}", path))
+                    }
+                    None => Some(draw_heatmap_in_terminal(&heightmap, &colors)),
+                }
+            }
+            "csv" => {
+                let csv = export_csv(&heightmap);
+
+                match params.path("out") {
+                    Some(path) => {
+                        fs::write(path, csv)
+                            .unwrap_or_else(|e| panic!("could not write {}: {}", path, e));
+                        Some(format!("trees exported to {}", path))
+                    }
+                    None => Some(csv),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn is_file_param(&self, key: &str, _value: &str) -> bool {
+        matches!(key, "image" | "out")
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Shade {
+    /// From dark blue for the least scenic trees to bright yellow for the most scenic ones.
+    Scenic,
+    /// Green for trees that are visible from outside, and gray for hidden ones (both brighter the taller they are).
+    Visibility,
+}
+
+impl Shade {
+    /// The color of every tree, in the same order as the heightmap.
+    fn colors(&self, heightmap: &Heightmap) -> Vec<[u8; 3]> {
+        let lerp = |from: u8, to: u8, t: f64| (from as f64 + (to as f64 - from as f64) * t) as u8;
+
+        match self {
+            Shade::Scenic => {
+                let scenic_scores = heightmap.scenic_scores();
+                let highest_score = scenic_scores.iter().copied().max().unwrap_or(0);
+
+                scenic_scores
+                    .iter()
+                    .map(|score| {
+                        // scores grow multiplicatively, so a log scale tells them apart much better
+                        let t = if highest_score == 0 {
+                            0.0
+                        } else {
+                            (*score as f64).ln_1p() / (highest_score as f64).ln_1p()
+                        };
+                        [lerp(20, 250, t), lerp(20, 220, t), lerp(90, 40, t)]
+                    })
+                    .collect()
+            }
+            Shade::Visibility => heightmap
+                .heights
+                .iter()
+                .zip(heightmap.visibility())
+                .map(|(height, sides)| {
+                    let t = *height as f64 / 9.0;
+                    if sides != 0 {
+                        [lerp(20, 90, t), lerp(90, 230, t), lerp(20, 90, t)]
+                    } else {
+                        [lerp(30, 90, t), lerp(30, 90, t), lerp(30, 90, t)]
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Draws every tree's height on a background of its color.
+fn draw_heatmap_in_terminal(heightmap: &Heightmap, colors: &[[u8; 3]]) -> String {
+    heightmap
+        .heights
+        .iter()
+        .zip(colors)
+        .map(|(height, [r, g, b])| format!("\x1b[48;2;{};{};{}m{}\x1b[0m", r, g, b, height))
+        .chunks(heightmap.width)
+        .into_iter()
+        .map(|mut row| row.join(""))
+        .join("\n")
+}
+
+const PIXELS_PER_TREE: usize = 4;
+
+fn write_heatmap_image(heightmap: &Heightmap, colors: &[[u8; 3]], path: &str) {
+    let mut image = format!(
+        "P6\n{} {}\n255\n",
+        heightmap.width * PIXELS_PER_TREE,
+        heightmap.height * PIXELS_PER_TREE
+    )
+    .into_bytes();
+
+    for row in colors.chunks(heightmap.width.max(1)) {
+        let scaled_row = row
+            .iter()
+            .flat_map(|color| color.repeat(PIXELS_PER_TREE))
+            .collect_vec();
+
+        for _ in 0..PIXELS_PER_TREE {
+            image.write_all(&scaled_row).unwrap();
+        }
+    }
+
+    fs::write(path, image).unwrap_or_else(|e| panic!("could not write {}: {}", path, e));
+}
+
+fn export_csv(heightmap: &Heightmap) -> String {
+    let header = "x,y,height,visible_from,distance_to_left,distance_to_top,distance_to_right,distance_to_bottom,scenic_score";
+
+    let visibility = heightmap.visibility();
+    let distances = Direction::ALL.map(|direction| heightmap.viewing_distances(direction));
+
+    let rows = heightmap.heights.iter().enumerate().map(|(i, height)| {
+        let visible_from = Direction::ALL
+            .iter()
+            .filter(|direction| visibility[i] & direction.bit() != 0)
+            .map(|direction| direction.name())
+            .join("|");
+        let (x, y) = heightmap.position(i);
+        let [left, top, right, bottom] = distances.each_ref().map(|d| d[i]);
+
+        format!(
+            "{},{},{},{},{},{},{},{},{}",
+            x,
+            y,
+            height,
+            visible_from,
+            left,
+            top,
+            right,
+            bottom,
+            left * top * right * bottom
+        )
+    });
+
+    [header.to_string()].into_iter().chain(rows).join("\n")
+}
+
+/// A side of the forest. Sweeping from a side means walking along every line of trees starting at that side.
+#[derive(Clone, Copy)]
+enum Direction {
+    Left,
+    Top,
+    Right,
+    Bottom,
+}
+
+impl Direction {
+    const ALL: [Direction; 4] = [
+        Direction::Left,
+        Direction::Top,
+        Direction::Right,
+        Direction::Bottom,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Direction::Left => "left",
+            Direction::Top => "top",
+            Direction::Right => "right",
+            Direction::Bottom => "bottom",
+        }
+    }
+
+    /// The bit that marks a tree as visible from this side.
+    fn bit(&self) -> u8 {
+        1 << *self as u8
+    }
+}
+
+/// The heights of all trees, row after row.
+struct Heightmap {
+    heights: Vec<u8>,
+    width: usize,
+    height: usize,
+}
+
+impl Heightmap {
+    fn parse(input: &str) -> Self {
+        let mut heights = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.trim_end().lines().enumerate() {
+            let line = line.trim_end();
+            let trees = line.chars().count();
+            match width {
+                None => width = Some(trees),
+                Some(width) if width != trees => panic!(
+                    "invalid forest, line {}: expected {} trees, found {}",
+                    y + 1,
+                    width,
+                    trees
+                ),
+                _ => {}
+            }
+
+            for char in line.chars() {
+                let tree_height = char
+                    .to_digit(10)
+                    .unwrap_or_else(|| panic!("char is not a valid digit: {}", char));
+                heights.push(tree_height as u8);
+            }
+            height += 1;
+        }
+
+        let width = width.unwrap_or(0);
+        if width == 0 {
+            panic!("invalid forest: there are no trees");
+        }
+
+        Self {
+            heights,
+            width,
+            height,
+        }
+    }
+
+    /// The (x, y) position of the tree at the given index.
+    fn position(&self, index: usize) -> (usize, usize) {
+        (index % self.width, index / self.width)
+    }
+
+    /// Every line of trees, as the indices of its trees in the order we pass them when sweeping from the given side.
+    fn lines_from(
+        &self,
+        direction: Direction,
+    ) -> impl Iterator<Item = impl Iterator<Item = usize>> + use<> {
+        let (width, height) = (self.width as isize, self.height as isize);
+
+        // how many lines there are, where the first one starts, how far apart the lines start,
+        // how far apart the trees along a line are, and how many trees every line has
+        let (lines, first, between_lines, between_trees, length) = match direction {
+            Direction::Left => (height, 0, width, 1, width),
+            Direction::Right => (height, width - 1, width, -1, width),
+            Direction::Top => (width, 0, 1, width, height),
+            Direction::Bottom => (width, (height - 1) * width, 1, -width, height),
+        };
+
+        (0..lines).map(move |line| {
+            let start = first + line * between_lines;
+            (0..length).map(move |i| (start + i * between_trees) as usize)
+        })
+    }
+
+    /// Which sides every tree is visible from, as a set of `Direction` bits.
+    fn visibility(&self) -> Vec<u8> {
+        let mut visibility = vec![0; self.heights.len()];
+
+        for direction in Direction::ALL {
+            for line in self.lines_from(direction) {
+                let mut highest: Option<u8> = None;
+
+                for i in line {
+                    if highest.is_none_or(|highest| self.heights[i] > highest) {
+                        visibility[i] |= direction.bit();
+                        highest = Some(self.heights[i]);
+                    }
+                }
+            }
+        }
+
+        visibility
+    }
+
+    /// Calls `visit` with every tree's index and how far it can see towards the given side.
+    fn for_each_viewing_distance(&self, direction: Direction, mut visit: impl FnMut(usize, usize)) {
+        for line in self.lines_from(direction) {
+            // where along the line we last saw a tree of every height
+            let mut last_seen_at: [Option<usize>; 10] = [None; 10];
+
+            for (at, i) in line.enumerate() {
+                let height = self.heights[i] as usize;
+
+                let blocked_at = last_seen_at[height..].iter().flatten().max();
+                visit(
+                    i,
+                    match blocked_at {
+                        Some(blocked_at) => at - blocked_at,
+                        None => at,
+                    },
+                );
+
+                last_seen_at[height] = Some(at);
+            }
+        }
+    }
+
+    fn viewing_distances(&self, direction: Direction) -> Vec<usize> {
+        let mut distances = vec![0; self.heights.len()];
+        self.for_each_viewing_distance(direction, |i, distance| distances[i] = distance);
+        distances
+    }
+
+    fn scenic_scores(&self) -> Vec<usize> {
+        let mut scores = vec![1; self.heights.len()];
+        for direction in Direction::ALL {
+            self.for_each_viewing_distance(direction, |i, distance| scores[i] *= distance);
+        }
+        scores
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_non_square_forests() {
+        let heightmap = Heightmap::parse("123\n456\n");
+
+        assert_eq!((heightmap.width, heightmap.height), (3, 2));
+        assert_eq!(heightmap.position(4), (1, 1));
+        assert!(heightmap.visibility().iter().all(|sides| *sides != 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "char is not a valid digit: é")]
+    fn counts_the_width_in_characters() {
+        Heightmap::parse("123\n1é3");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid forest, line 2: expected 3 trees, found 2")]
+    fn rejects_rows_of_different_widths() {
+        Heightmap::parse("123\n12");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid forest: there are no trees")]
+    fn rejects_empty_forests() {
+        Heightmap::parse("\n");
+    }
+}
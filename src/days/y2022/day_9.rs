@@ -0,0 +1,246 @@
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::day::{Day, Params};
+use crate::visualize::{self, Frame};
+
+#[derive(Clone, Copy)]
+pub struct Day9 {}
+
+impl Day for Day9 {
+    fn title(&self) -> &'static str {
+        "Day 9: Rope Bridge"
+    }
+
+    fn description(&self) -> &'static str {
+        "
+        We use a Rope struct and a set of coords (i64, i64).
+        In both tasks we move the rope and then register its position in the set.
+        Since sets only hold the same values once, all we have to do eventually is to just check the size of the set.
+
+        In order to complete task 1, we created a Rope struct with head and tail. We implement the logic that moves the tail
+        in accordance to the head.
+
+        Task 2 introduces a longer rope. In order to support 10 knots, we update the Rope struct to hold a vector of all of its
+        knots, the first one being the head and the last one being the tail.
+
+        Now, after moving the head, we iteratively move each following knot in order. After each iteration, we make
+        the most recent knot the \"head\" of the next one.
+
+        That way, this struct works both in task 1 (with 2 knots), and in task 2 (with 10 knots) - or with a rope of any length,
+        given by `--param knots=<N>`. Since we track the positions of all knots, both tasks also report how many unique
+        positions each of the knots visited, not just the tail.
+        "
+    }
+
+    fn task_1(&self, input: &str, params: &Params) -> String {
+        let steps = parse_input_into_steps(input);
+
+        let rope = Rope::new(params.parse("knots", 2));
+
+        describe_positions_visited(&count_positions_visited_by_knots(&steps, rope))
+    }
+
+    fn task_2(&self, input: &str, params: &Params) -> String {
+        let steps = parse_input_into_steps(input);
+
+        let rope = Rope::new(params.parse("knots", 10));
+
+        describe_positions_visited(&count_positions_visited_by_knots(&steps, rope))
+    }
+}
+
+fn describe_positions_visited(positions_visited_by_knots: &[usize]) -> String {
+    let last = positions_visited_by_knots.len() - 1;
+
+    let per_knot = positions_visited_by_knots
+        .iter()
+        .enumerate()
+        .map(|(i, count)| match i {
+            0 => format!("head: {}", count),
+            i if i == last => format!("tail: {}", count),
+            i => format!("knot {}: {}", i, count),
+        })
+        .join(", ");
+
+    format!(
+        "the tail visited {} unique locations (by knot - {})",
+        positions_visited_by_knots[last], per_knot
+    )
+}
+
+/// Returns the number of unique positions visited by each of the rope's knots, from the head to the tail.
+fn count_positions_visited_by_knots(steps: &[Step], mut rope: Rope) -> Vec<usize> {
+    let mut sets_of_visited_positions = rope
+        .knots
+        .iter()
+        .map(|knot| HashSet::<Position>::from([*knot]))
+        .collect_vec();
+
+    for step in steps {
+        let (move_rope, by): (fn(&mut Rope), &i64) = match step {
+            Step::Left(by) => (Rope::left, by),
+            Step::Up(by) => (Rope::up, by),
+            Step::Right(by) => (Rope::right, by),
+            Step::Down(by) => (Rope::down, by),
+        };
+
+        for _ in 0..*by {
+            move_rope(&mut rope);
+            for (knot, visited_positions) in rope.knots.iter().zip(&mut sets_of_visited_positions) {
+                visited_positions.insert(*knot);
+            }
+            visualize::emit(|| rope.draw(sets_of_visited_positions.last().unwrap()));
+        }
+    }
+
+    sets_of_visited_positions
+        .iter()
+        .map(|set| set.len())
+        .collect()
+}
+
+type Position = (i64, i64);
+
+/// All of the rope's knots, from the head to the tail.
+struct Rope {
+    knots: Vec<Position>,
+}
+
+impl Rope {
+    fn new(amount_of_knots: usize) -> Self {
+        if amount_of_knots == 0 {
+            panic!("a rope needs at least one knot");
+        }
+
+        Self {
+            knots: vec![(0, 0); amount_of_knots],
+        }
+    }
+
+    fn head(&self) -> &Position {
+        self.knots.first().unwrap()
+    }
+
+    fn tail(&self) -> &Position {
+        self.knots.last().unwrap()
+    }
+
+    fn left(&mut self) {
+        self.move_by(&(-1, 0))
+    }
+
+    fn up(&mut self) {
+        self.move_by(&(0, -1))
+    }
+
+    fn right(&mut self) {
+        self.move_by(&(1, 0))
+    }
+
+    fn down(&mut self) {
+        self.move_by(&(0, 1))
+    }
+
+    fn move_by(&mut self, by: &Position) {
+        let (head, following_knots) = self.knots.split_first_mut().unwrap();
+
+        head.0 += by.0;
+        head.1 += by.1;
+
+        let mut lead_position = &*head;
+
+        for knot in following_knots.iter_mut() {
+            let new_knot_position = Rope::calculate_knot_position(knot, lead_position);
+
+            knot.0 = new_knot_position.0;
+            knot.1 = new_knot_position.1;
+
+            lead_position = knot;
+        }
+    }
+
+    /// Draws the surroundings of the head, along with every position the tail has visited so far.
+    fn draw(&self, visited_by_tail: &HashSet<Position>) -> Frame {
+        const FRAME_WIDTH: usize = 61;
+        const FRAME_HEIGHT: usize = 31;
+
+        let mut frame = Frame::new(FRAME_WIDTH, FRAME_HEIGHT, '.');
+        let top_left = (
+            self.head().0 - (FRAME_WIDTH / 2) as i64,
+            self.head().1 - (FRAME_HEIGHT / 2) as i64,
+        );
+        let mut draw_at = |position: &Position, c: char| {
+            let (x, y) = (position.0 - top_left.0, position.1 - top_left.1);
+            if x >= 0 && y >= 0 {
+                frame.set(x as usize, y as usize, c);
+            }
+        };
+
+        visited_by_tail.iter().for_each(|p| draw_at(p, '#'));
+        draw_at(self.tail(), 'T');
+        self.knots
+            .iter()
+            .skip(1)
+            .rev()
+            .skip(1)
+            .for_each(|knot| draw_at(knot, '*'));
+        draw_at(self.head(), 'H');
+
+        frame
+    }
+
+    fn calculate_knot_position(knot: &Position, lead_position: &Position) -> Position {
+        let (cur_lead_x, cur_lead_y) = lead_position.clone();
+
+        match knot.clone() {
+            (x, y) if y == cur_lead_y => {
+                if (cur_lead_x - x).abs() > 1 {
+                    let step = if cur_lead_x > x { 1 } else { -1 };
+                    (x + step, y)
+                } else {
+                    (x, y)
+                }
+            }
+            (x, y) if x == cur_lead_x => {
+                if (cur_lead_y - y).abs() > 1 {
+                    let step = if cur_lead_y > y { 1 } else { -1 };
+                    (x, y + step)
+                } else {
+                    (x, y)
+                }
+            }
+            (x, y) if (x - cur_lead_x).abs() == 1 && (y - cur_lead_y).abs() == 1 => (x, y),
+            (x, y) => {
+                let step_x = if cur_lead_x > x { 1 } else { -1 };
+                let step_y = if cur_lead_y > y { 1 } else { -1 };
+                (x + step_x, y + step_y)
+            }
+        }
+    }
+}
+
+enum Step {
+    Left(i64),
+    Up(i64),
+    Right(i64),
+    Down(i64),
+}
+
+fn parse_input_into_steps(input: &str) -> Vec<Step> {
+    input
+        .lines()
+        .map(|line| {
+            match (
+                line.split(" ").nth(0).unwrap(),
+                line.split(" ").nth(1).unwrap(),
+            ) {
+                ("L", by) => Step::Left(by.parse::<i64>().unwrap()),
+                ("U", by) => Step::Up(by.parse::<i64>().unwrap()),
+                ("R", by) => Step::Right(by.parse::<i64>().unwrap()),
+                ("D", by) => Step::Down(by.parse::<i64>().unwrap()),
+                _ => panic!("unknown input"),
+            }
+        })
+        .collect_vec()
+}
@@ -0,0 +1,40 @@
+use crate::day::Day;
+
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17_unfinished;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+/// All implemented days of the 2022 event, along with their number in the competition.
+pub fn all() -> [(usize, &'static dyn Day); 16] {
+    [
+        (2, &day_2::Day2 {}),
+        (3, &day_3::Day3 {}),
+        (4, &day_4::Day4 {}),
+        (5, &day_5::Day5 {}),
+        (6, &day_6::Day6 {}),
+        (7, &day_7::Day7 {}),
+        (8, &day_8::Day8 {}),
+        (9, &day_9::Day9 {}),
+        (10, &day_10::Day10 {}),
+        (11, &day_11::Day11 {}),
+        (12, &day_12::Day12 {}),
+        (13, &day_13::Day13 {}),
+        (14, &day_14::Day14 {}),
+        (15, &day_15::Day15 {}),
+        (16, &day_16::Day16 {}),
+        (17, &day_17_unfinished::Day17 {}),
+    ]
+}
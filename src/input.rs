@@ -1,12 +1,26 @@
 use std::fs;
-use std::path::Path;
+use std::io;
+use std::path::{Path, PathBuf};
 
 const INPUT_DIR: &str = "./assets/inputs";
 
-pub fn input_for_day(day: u8) -> String {
-    let filename = format!("day{}.txt", day);
-    let path_to_input = Path::new(INPUT_DIR).join(&filename);
-    let contents = fs::read_to_string(path_to_input);
+pub fn input_for_day(year: u16, day: u8) -> String {
+    try_input_for_day(year, day).unwrap_or_else(|e| {
+        panic!(
+            "missing input file: {}: {:?}",
+            path_to_input(year, day).display(),
+            e
+        )
+    })
+}
+
+pub fn try_input_for_day(year: u16, day: u8) -> io::Result<String> {
+    fs::read_to_string(path_to_input(year, day))
+}
 
-    contents.expect(format!("missing input file: {}", filename).as_str())
+/// Inputs are kept per event year: `assets/inputs/<year>/day<day>.txt`
+pub fn path_to_input(year: u16, day: u8) -> PathBuf {
+    Path::new(INPUT_DIR)
+        .join(year.to_string())
+        .join(format!("day{}.txt", day))
 }
@@ -1,92 +1,158 @@
 mod day;
 mod days;
 mod input;
+mod patterns;
+mod server;
+mod tui;
+mod visualize;
 
-use clap::{ArgAction, Parser};
-
-use crate::day::Day;
-use crate::days::day_10::Day10;
-use crate::days::day_11::Day11;
-use crate::days::day_12::Day12;
-use crate::days::day_13::Day13;
-use crate::days::day_14::Day14;
-use crate::days::day_15::Day15;
-use crate::days::day_16::Day16;
-use crate::days::day_17_unfinished::Day17;
-use crate::days::day_2::Day2;
-use crate::days::day_3::Day3;
-use crate::days::day_4::Day4;
-use crate::days::day_5::Day5;
-use crate::days::day_6::Day6;
-use crate::days::day_7::Day7;
-use crate::days::day_8::Day8;
-use crate::days::day_9::Day9;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{ArgAction, Parser, Subcommand};
+
+use crate::day::Params;
+use crate::input::input_for_day;
+use crate::visualize::Backend;
 
 #[derive(Parser)]
+#[command(subcommand_negates_reqs = true)]
 struct CLI {
-    #[arg(long, help = "which day of the competition to run [2-30]")]
-    day: usize,
-    #[arg(long, help = "which task to run [1-2]")]
-    task: u8,
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[arg(long, default_value_t = days::LATEST_YEAR, help = "which year's event to run")]
+    year: u16,
+    #[arg(
+        long,
+        required = true,
+        help = "which day of the competition to run [1-25]"
+    )]
+    day: Option<usize>,
+    #[arg(
+        long,
+        required = true,
+        help = "which task to run [1-2], or the name of one of the day's extra tasks"
+    )]
+    task: Option<String>,
     #[arg(
         long,
         action = ArgAction::SetTrue,
         help = "whether or not to display a description of the solution"
     )]
     describe: Option<bool>,
+    #[arg(
+        long = "param",
+        short = 'p',
+        value_parser = day::parse_param,
+        help = "a day-specific parameter, given as key=value (see each day's description)"
+    )]
+    params: Vec<(String, String)>,
+    #[arg(
+        long,
+        value_enum,
+        help = "visualize the simulation of days that support it (2022: 9, 10, 14, 17)"
+    )]
+    visualize: Option<Backend>,
+    #[arg(
+        long,
+        default_value = "./assets/frames",
+        help = "where to write frames when visualizing as ppm or gif"
+    )]
+    frames_dir: PathBuf,
+    #[arg(
+        long,
+        global = true,
+        default_value_t = 1,
+        help = "only keep every nth frame"
+    )]
+    frame_every: usize,
+    #[arg(
+        long,
+        global = true,
+        default_value_t = 50,
+        help = "delay between frames in ms"
+    )]
+    frame_delay: u64,
 }
 
-fn main() {
-    let days: [Box<dyn Day>; 16] = [
-        Box::from(Day2 {}),
-        Box::from(Day3 {}),
-        Box::from(Day4 {}),
-        Box::from(Day5 {}),
-        Box::from(Day6 {}),
-        Box::from(Day7 {}),
-        Box::from(Day8 {}),
-        Box::from(Day9 {}),
-        Box::from(Day10 {}),
-        Box::from(Day11 {}),
-        Box::from(Day12 {}),
-        Box::from(Day13 {}),
-        Box::from(Day14 {}),
-        Box::from(Day15 {}),
-        Box::from(Day16 {}),
-        Box::from(Day17 {}),
-    ];
+#[derive(Subcommand)]
+enum Command {
+    /// Browse the days, their descriptions and results in an interactive terminal UI
+    Tui,
+    /// Serve the days over a local HTTP API
+    Serve {
+        #[arg(long, default_value = "127.0.0.1", help = "address to listen on")]
+        host: String,
+        #[arg(long, default_value_t = 2022, help = "port to listen on")]
+        port: u16,
+    },
+}
 
+fn main() {
     let args = CLI::parse();
 
+    match args.command {
+        Some(Command::Tui) => {
+            tui::run(args.frame_every, Duration::from_millis(args.frame_delay));
+            return;
+        }
+        Some(Command::Serve { host, port }) => {
+            server::serve(&host, port);
+            return;
+        }
+        None => {}
+    }
+
+    let day_number = args.day.expect("clap makes sure that day is provided");
+    let task = args.task.expect("clap makes sure that task is provided");
+
+    assert!(
+        days::years().contains(&args.year),
+        "no solutions for year {} (available years: {:?})",
+        args.year,
+        days::years()
+    );
+
     assert!(
-        1 < args.day && args.day < 31,
-        "invalid day (expected value between 2 to 30"
+        (1..=25).contains(&day_number),
+        "invalid day (expected value between 1 to 25)"
     );
 
-    let day = days
-        .get(args.day - 2)
-        .expect(format!("day does not exist (day: {})", args.day).as_str());
+    let day = days::get(args.year, day_number).unwrap_or_else(|| {
+        panic!(
+            "day does not exist (year: {}, day: {})",
+            args.year, day_number
+        )
+    });
 
     assert!(
-        0 < args.task && args.task < 3,
-        "invalid task index, expected 1 or 2"
+        day::task_names(day).contains(&task.as_str()),
+        "invalid task, expected one of {:?}",
+        day::task_names(day)
     );
 
-    println!("Advent of Code 2022");
+    println!("Advent of Code {}", args.year);
     println!("");
-    println!("Day {}", args.day);
+    println!("Day {}", day_number);
     println!("{}", day.title());
     if args.describe == Some(true) {
         println!("{}", day.description());
     }
     println!("");
-    println!("Task: {}", args.task);
-    println!(
-        "Result: {}",
-        match args.task {
-            1 => day.task_1(),
-            2 => day.task_2(),
-            _ => panic!("task should've been between 1 to 2. No idea what happened"),
-        }
-    );
+
+    let input = input_for_day(args.year, day_number as u8);
+    let params = Params::from_pairs(args.params);
+
+    if let Some(backend) = args.visualize {
+        let sink = backend.create_sink(args.frames_dir, Duration::from_millis(args.frame_delay));
+        visualize::install_sink(sink, args.frame_every);
+    }
+
+    let result = day::run_named_task(day, &task, &input, &params)
+        .expect("task should've been one of the day's tasks. No idea what happened");
+
+    visualize::finish();
+
+    println!("Task: {}", task);
+    println!("Result: {}", result);
 }
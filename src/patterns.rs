@@ -0,0 +1,13 @@
+/// Returns a `&'static Regex` for the given pattern literal.
+///
+/// The regex is compiled the first time the expression is evaluated, and every evaluation
+/// afterwards (e.g. parsing the next line of the input) reuses the same compiled instance.
+macro_rules! regex {
+    ($pattern:literal) => {{
+        static PATTERN: std::sync::LazyLock<regex::Regex> =
+            std::sync::LazyLock::new(|| regex::Regex::new($pattern).unwrap());
+        &*PATTERN
+    }};
+}
+
+pub(crate) use regex;
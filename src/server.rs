@@ -0,0 +1,219 @@
+use std::thread;
+
+use json::{object, JsonValue};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::day::{parse_param, render_description, run_task, task_names, Day, Params};
+use crate::days;
+
+/// Serves the days over HTTP until the process is killed:
+///
+/// - `GET /days`: every day's number and title
+/// - `GET /days/{n}`: the day's number, title, description and the names of its tasks
+/// - `POST /days/{n}/tasks/{t}`: runs a task with the request body as input (and day-specific params as query params),
+///   responding with its result and timing. Params that refer to files are rejected, as anyone who can reach the
+///   server could otherwise read and write files as the server does.
+///
+/// These refer to the latest event year, and are available for other years under `/years/{year}/...`
+pub fn serve(host: &str, port: u16) {
+    let address = format!("{}:{}", host, port);
+    let server =
+        Server::http(&address).unwrap_or_else(|e| panic!("could not listen on {}: {}", address, e));
+
+    println!("Listening on http://{}", address);
+
+    for request in server.incoming_requests() {
+        // tasks may take a while, so we don't want them to block each other
+        thread::spawn(move || handle_request(request));
+    }
+}
+
+fn handle_request(mut request: Request) {
+    let (path, query) = request.url().split_once('?').unwrap_or((request.url(), ""));
+    let path_segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(|segment| segment.to_string())
+        .collect::<Vec<_>>();
+    let path_segments = path_segments.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+    // day-specific params are given as query params, e.g. `?rules=rpsls`
+    let params = query
+        .split('&')
+        .filter_map(|param| parse_param(param).ok())
+        .map(|(key, value)| {
+            Ok((
+                decode_query_component(&key)?,
+                decode_query_component(&value)?,
+            ))
+        })
+        .collect::<Result<Vec<_>, String>>()
+        .map(|params| Params::from_pairs(params).sandboxed());
+
+    let (year, path_segments) = match path_segments.as_slice() {
+        ["years", year, rest @ ..] => match year.parse::<u16>() {
+            Ok(year) => (year, rest),
+            Err(_) => (0, rest),
+        },
+        rest => (days::LATEST_YEAR, rest),
+    };
+
+    let (status, body) = match (request.method(), path_segments) {
+        (Method::Get, ["days"]) => list_days(year),
+        (Method::Get, ["days", day]) => describe_day(year, day),
+        (Method::Post, ["days", day, "tasks", task]) => {
+            let mut input = String::new();
+            match (request.as_reader().read_to_string(&mut input), params) {
+                (Ok(_), Ok(params)) => run_day_task(year, day, task, &input, &params),
+                (Err(e), _) => error(400, format!("could not read input from body: {}", e)),
+                (_, Err(message)) => error(400, message),
+            }
+        }
+        _ => error(404, "not found"),
+    };
+
+    let content_type = Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+        .expect("content type header is valid");
+    let response = Response::from_string(body.dump())
+        .with_status_code(status)
+        .with_header(content_type);
+
+    if let Err(e) = request.respond(response) {
+        eprintln!("could not respond to request: {}", e);
+    }
+}
+
+fn list_days(year: u16) -> (u16, JsonValue) {
+    if !days::years().contains(&year) {
+        return error(404, format!("no solutions for year {}", year));
+    }
+
+    let days = days::all()
+        .iter()
+        .filter(|(y, _, _)| *y == year)
+        .map(|(year, number, day)| {
+            object! {
+                year: *year,
+                day: *number,
+                title: day.title(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    (200, JsonValue::Array(days))
+}
+
+fn find_day(year: u16, day: &str) -> Option<(usize, &'static dyn Day)> {
+    let number = day.parse().ok()?;
+    days::get(year, number).map(|d| (number, d))
+}
+
+fn describe_day(year: u16, day: &str) -> (u16, JsonValue) {
+    let Some((number, day)) = find_day(year, day) else {
+        return error(
+            404,
+            format!("day does not exist (year: {}, day: {})", year, day),
+        );
+    };
+
+    (
+        200,
+        object! {
+            year: year,
+            day: number,
+            title: day.title(),
+            description: render_description(day.description()),
+            tasks: task_names(day),
+        },
+    )
+}
+
+fn run_day_task(
+    year: u16,
+    day: &str,
+    task: &str,
+    input: &str,
+    params: &Params,
+) -> (u16, JsonValue) {
+    let Some((number, day)) = find_day(year, day) else {
+        return error(
+            404,
+            format!("day does not exist (year: {}, day: {})", year, day),
+        );
+    };
+
+    if !task_names(day).contains(&task) {
+        return error(
+            404,
+            format!(
+                "invalid task, expected one of {:?}: {}",
+                task_names(day),
+                task
+            ),
+        );
+    }
+
+    if let Some((key, _)) = params
+        .iter()
+        .find(|(key, value)| day.is_file_param(key, value))
+    {
+        return error(
+            400,
+            format!(
+                "param {} refers to a file, which is not allowed over HTTP",
+                key
+            ),
+        );
+    }
+
+    match run_task(day, task, input, params) {
+        (Ok(result), took) => (
+            200,
+            object! {
+                year: year,
+                day: number,
+                task: task,
+                result: result,
+                took_ms: took.as_secs_f64() * 1000.0,
+            },
+        ),
+        (Err(message), took) => (
+            422,
+            object! {
+                year: year,
+                day: number,
+                task: task,
+                error: message,
+                took_ms: took.as_secs_f64() * 1000.0,
+            },
+        ),
+    }
+}
+
+/// Decodes a key or a value of the query string, where `+` stands for a space and `%XX` for any byte.
+fn decode_query_component(component: &str) -> Result<String, String> {
+    let invalid = || format!("invalid percent-encoding in query: {}", component);
+
+    let mut decoded = vec![];
+    let mut bytes = component.bytes();
+    while let Some(byte) = bytes.next() {
+        match byte {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                let mut hex_digit = || {
+                    bytes
+                        .next()
+                        .and_then(|digit| (digit as char).to_digit(16))
+                        .ok_or_else(invalid)
+                };
+                decoded.push((hex_digit()? * 16 + hex_digit()?) as u8);
+            }
+            byte => decoded.push(byte),
+        }
+    }
+
+    String::from_utf8(decoded).map_err(|_| format!("query is not valid UTF-8: {}", component))
+}
+
+fn error(status: u16, message: impl Into<String>) -> (u16, JsonValue) {
+    (status, object! { error: message.into() })
+}
@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+use std::panic;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListState, Paragraph, Wrap};
+use ratatui::DefaultTerminal;
+
+use crate::day::{render_description, run_task, task_names, Day, Params, TaskOutcome};
+use crate::days;
+use crate::input::{path_to_input, try_input_for_day};
+use crate::visualize::{self, Frame, FrameSink};
+
+const TASK_RUNNER_THREAD_NAME: &str = "task-runner";
+
+/// The latest frame of a running task that the UI has not shown yet.
+type FrameSlot = Arc<Mutex<Option<Frame>>>;
+
+/// Hands the frames of a running task over to the UI thread. The task never waits for the UI (so visualizing
+/// doesn't slow it down): the UI shows a frame whenever it's time for the next one, and frames that come in
+/// between are skipped.
+struct SlotSink {
+    slot: FrameSlot,
+}
+
+impl FrameSink for SlotSink {
+    fn frame(&mut self, frame: &Frame) {
+        *self.slot.lock().expect("frame slot is never poisoned") = Some(frame.clone());
+    }
+}
+
+/// Identifies a day by its event year and number.
+type DayId = (u16, usize);
+
+struct RunningTask {
+    day: DayId,
+    task: &'static str,
+    started_at: Instant,
+    frames: FrameSlot,
+    outcome: Receiver<TaskOutcome>,
+}
+
+struct App {
+    days: Vec<(u16, usize, &'static dyn Day)>,
+    list_state: ListState,
+    description_scroll: u16,
+    outcomes: HashMap<(DayId, &'static str), TaskOutcome>,
+    running: Option<RunningTask>,
+    latest_frame: Option<(DayId, Frame)>,
+    latest_frame_shown_at: Instant,
+    visualize: bool,
+    frame_every: usize,
+    frame_delay: Duration,
+}
+
+impl App {
+    fn new(frame_every: usize, frame_delay: Duration) -> Self {
+        Self {
+            days: days::all(),
+            list_state: ListState::default().with_selected(Some(0)),
+            description_scroll: 0,
+            outcomes: HashMap::new(),
+            running: None,
+            latest_frame: None,
+            latest_frame_shown_at: Instant::now(),
+            visualize: true,
+            frame_every,
+            frame_delay,
+        }
+    }
+
+    fn selected_day(&self) -> (DayId, &'static dyn Day) {
+        let (year, day_number, day) = self.days[self.list_state.selected().unwrap_or(0)];
+        ((year, day_number), day)
+    }
+
+    fn select(&mut self, offset: isize) {
+        let last_index = self.days.len() as isize - 1;
+        let current = self.list_state.selected().unwrap_or(0) as isize;
+
+        self.list_state
+            .select(Some((current + offset).clamp(0, last_index) as usize));
+        self.description_scroll = 0;
+    }
+
+    /// Runs the nth task of the selected day (1 and 2 being the puzzle's tasks, followed by the day's extra tasks).
+    fn run_task(&mut self, task_number: usize) {
+        if self.running.is_some() {
+            return;
+        }
+
+        let (day_id, day) = self.selected_day();
+        let Some(task) = task_names(day).get(task_number - 1).copied() else {
+            return;
+        };
+        let (sender, outcome) = mpsc::channel();
+        let frames = FrameSlot::default();
+        let sink = self.visualize.then(|| SlotSink {
+            slot: frames.clone(),
+        });
+        let frame_every = self.frame_every;
+
+        thread::Builder::new()
+            .name(TASK_RUNNER_THREAD_NAME.to_string())
+            .spawn(move || {
+                if let Some(sink) = sink {
+                    visualize::install_sink(Box::from(sink), frame_every);
+                }
+
+                let (year, day_number) = day_id;
+                let outcome = match try_input_for_day(year, day_number as u8) {
+                    Ok(input) => run_task(day, task, &input, &Params::default()),
+                    Err(e) => {
+                        let path = path_to_input(year, day_number as u8);
+                        let message = format!("missing input file: {}: {}", path.display(), e);
+                        (Err(message), Duration::ZERO)
+                    }
+                };
+
+                visualize::finish();
+                let _ = sender.send(outcome);
+            })
+            .expect("could not spawn a thread to run the task on");
+
+        self.latest_frame = None;
+        self.running = Some(RunningTask {
+            day: day_id,
+            task,
+            started_at: Instant::now(),
+            frames,
+            outcome,
+        });
+    }
+
+    /// Consumes whatever the running task sent since the last check. Returns whether the task has finished.
+    fn poll_running_task(&mut self) -> bool {
+        let Some(running) = &self.running else {
+            return false;
+        };
+
+        let finished_with = running.outcome.try_recv().ok();
+
+        // frames are shown at most once every frame delay, but the last one is shown as soon as the task finishes
+        if finished_with.is_some() || self.latest_frame_shown_at.elapsed() >= self.frame_delay {
+            let frame = running
+                .frames
+                .lock()
+                .expect("frame slot is never poisoned")
+                .take();
+            if let Some(frame) = frame {
+                self.latest_frame = Some((running.day, frame));
+                self.latest_frame_shown_at = Instant::now();
+            }
+        }
+
+        match finished_with {
+            Some(outcome) => {
+                self.outcomes.insert((running.day, running.task), outcome);
+                self.running = None;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn draw(&mut self, frame: &mut ratatui::Frame) {
+        let [days_area, day_area] =
+            Layout::horizontal([Constraint::Length(39), Constraint::Min(0)]).areas(frame.area());
+
+        let day_items = self
+            .days
+            .iter()
+            .map(|(year, number, day)| format!("{} {:>2}. {}", year, number, day.title()))
+            .collect_vec();
+        let days_list = List::new(day_items)
+            .block(Block::bordered().title(" Days "))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(days_list, days_area, &mut self.list_state);
+
+        let (day_id, day) = self.selected_day();
+        let frame_of_selected_day = self
+            .latest_frame
+            .as_ref()
+            .filter(|(frame_day, _)| *frame_day == day_id)
+            .map(|(_, frame)| frame);
+
+        let visualization_height = frame_of_selected_day
+            .map(|f| f.height as u16 + 2)
+            .unwrap_or(0);
+        let [description_area, results_area, visualization_area] = Layout::vertical([
+            Constraint::Min(6),
+            Constraint::Length(10),
+            Constraint::Length(visualization_height),
+        ])
+        .areas(day_area);
+
+        let description = Paragraph::new(render_description(day.description()))
+            .wrap(Wrap { trim: false })
+            .scroll((self.description_scroll, 0))
+            .block(Block::bordered().title(format!(
+                " {} Day {}: {} ",
+                day_id.0,
+                day_id.1,
+                day.title()
+            )));
+        frame.render_widget(description, description_area);
+
+        frame.render_widget(self.results_paragraph(day_id), results_area);
+
+        if let Some(visualization) = frame_of_selected_day {
+            Self::draw_visualization(frame, visualization, visualization_area);
+        }
+    }
+
+    fn results_paragraph(&self, day_id: DayId) -> Paragraph<'static> {
+        let mut lines = vec![];
+
+        let (_, day) = self.selected_day();
+        for (number, task) in task_names(day).into_iter().enumerate() {
+            let running = self
+                .running
+                .as_ref()
+                .filter(|r| r.day == day_id && r.task == task);
+
+            if let Some(running) = running {
+                lines.push(Line::styled(
+                    format!(
+                        "Task {}: running... ({:.1?})",
+                        task,
+                        running.started_at.elapsed()
+                    ),
+                    Style::default().fg(Color::Yellow),
+                ));
+                continue;
+            }
+
+            match self.outcomes.get(&(day_id, task)) {
+                Some((Ok(result), took)) => {
+                    lines.push(Line::styled(
+                        format!("Task {} (took {:.2?}):", task, took),
+                        Style::default().fg(Color::Green),
+                    ));
+                    lines.extend(result.lines().map(|ln| Line::from(format!("  {}", ln))));
+                }
+                Some((Err(panic_message), took)) => {
+                    lines.push(Line::styled(
+                        format!("Task {} failed (after {:.2?}):", task, took),
+                        Style::default().fg(Color::Red),
+                    ));
+                    lines.push(Line::from(format!("  {}", panic_message)));
+                }
+                None => lines.push(Line::from(format!(
+                    "Task {}: press {} to run",
+                    task,
+                    number + 1
+                ))),
+            }
+        }
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::bordered().title(format!(
+                " Results (visualization: {}) ",
+                if self.visualize { "on" } else { "off" }
+            )))
+    }
+
+    fn draw_visualization(frame: &mut ratatui::Frame, visualization: &Frame, area: Rect) {
+        let visualization = Paragraph::new(visualization.to_text())
+            .block(Block::bordered().title(" Visualization "));
+        frame.render_widget(visualization, area);
+    }
+}
+
+/// Runs the interactive browser until the user quits.
+pub fn run(frame_every: usize, frame_delay: Duration) {
+    let mut terminal = ratatui::init();
+
+    // a failing task is reported in the UI, so we don't want its panic printed all over the screen
+    let default_panic_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if thread::current().name() != Some(TASK_RUNNER_THREAD_NAME) {
+            default_panic_hook(info);
+        }
+    }));
+
+    let result = run_app(&mut terminal, App::new(frame_every, frame_delay));
+
+    ratatui::restore();
+    result.expect("terminal ui failed");
+}
+
+fn run_app(terminal: &mut DefaultTerminal, mut app: App) -> std::io::Result<()> {
+    loop {
+        if app.poll_running_task() {
+            // some tasks print their progress to stdout, which leaves garbage on the screen
+            terminal.clear()?;
+        }
+
+        terminal.draw(|frame| app.draw(frame))?;
+
+        if !event::poll(Duration::from_millis(30))? {
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Up | KeyCode::Char('k') => app.select(-1),
+                KeyCode::Down | KeyCode::Char('j') => app.select(1),
+                KeyCode::PageUp => {
+                    app.description_scroll = app.description_scroll.saturating_sub(5)
+                }
+                KeyCode::PageDown => app.description_scroll += 5,
+                KeyCode::Char(c @ '1'..='9') => app.run_task(c as usize - '0' as usize),
+                KeyCode::Char('v') => app.visualize = !app.visualize,
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use std::cell::RefCell;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use itertools::Itertools;
+
+const PIXELS_PER_CELL: usize = 4;
+
+/// Every character a frame can be drawn with, and the color it gets in image backends.
+/// Characters that are not listed here are drawn with the first color.
+const PALETTE: [(char, [u8; 3]); 12] = [
+    (' ', [0, 0, 0]),
+    ('.', [24, 24, 32]),
+    ('#', [230, 230, 230]),
+    ('o', [235, 190, 90]),
+    ('@', [240, 120, 40]),
+    ('H', [220, 50, 50]),
+    ('T', [60, 200, 90]),
+    ('*', [90, 90, 200]),
+    ('|', [120, 120, 120]),
+    ('-', [120, 120, 120]),
+    ('+', [120, 120, 120]),
+    ('~', [70, 140, 220]),
+];
+
+/// A single state of a simulation, drawn as a grid of characters.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<char>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, background: char) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![background; width * height],
+        }
+    }
+
+    pub fn from_text(text: &str) -> Self {
+        let lines = text.lines().collect_vec();
+        let width = lines.iter().map(|ln| ln.chars().count()).max().unwrap_or(0);
+        let mut frame = Self::new(width, lines.len(), ' ');
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                frame.set(x, y, c);
+            }
+        }
+
+        frame
+    }
+
+    /// Draws a character on the frame. Anything outside of the frame is silently cropped.
+    pub fn set(&mut self, x: usize, y: usize, c: char) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = c;
+        }
+    }
+
+    pub fn to_text(&self) -> String {
+        self.cells
+            .chunks(self.width.max(1))
+            .map(|row| row.iter().collect::<String>())
+            .join("\n")
+    }
+
+    fn palette_index(c: char) -> u8 {
+        PALETTE.iter().position(|(p, _)| *p == c).unwrap_or(0) as u8
+    }
+
+    fn scaled_palette_indices(&self) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.cells.len() * PIXELS_PER_CELL * PIXELS_PER_CELL);
+
+        for row in self.cells.chunks(self.width.max(1)) {
+            let scaled_row = row
+                .iter()
+                .flat_map(|c| [Self::palette_index(*c); PIXELS_PER_CELL])
+                .collect_vec();
+
+            for _ in 0..PIXELS_PER_CELL {
+                pixels.extend_from_slice(&scaled_row);
+            }
+        }
+
+        pixels
+    }
+}
+
+/// Anything that can consume the frames emitted by a simulation.
+pub trait FrameSink {
+    fn frame(&mut self, frame: &Frame);
+    fn finish(&mut self) {}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Backend {
+    /// Play the frames in the terminal as an animation
+    Terminal,
+    /// Write every frame as a PPM image
+    Ppm,
+    /// Write all frames into a single animated GIF
+    Gif,
+}
+
+impl Backend {
+    pub fn create_sink(&self, out_dir: PathBuf, delay: Duration) -> Box<dyn FrameSink> {
+        match self {
+            Backend::Terminal => Box::from(TerminalPlayback { delay }),
+            Backend::Ppm => Box::from(PpmSequence::new(out_dir)),
+            Backend::Gif => Box::from(GifRecording::new(out_dir.join("animation.gif"), delay)),
+        }
+    }
+}
+
+pub struct TerminalPlayback {
+    delay: Duration,
+}
+
+impl FrameSink for TerminalPlayback {
+    fn frame(&mut self, frame: &Frame) {
+        println!("\x1b[2J\x1b[H{}", frame.to_text());
+        std::io::stdout().flush().unwrap();
+        thread::sleep(self.delay);
+    }
+}
+
+pub struct PpmSequence {
+    out_dir: PathBuf,
+    frame_count: usize,
+}
+
+impl PpmSequence {
+    fn new(out_dir: PathBuf) -> Self {
+        fs::create_dir_all(&out_dir)
+            .unwrap_or_else(|_| panic!("could not create frames dir: {}", out_dir.display()));
+
+        Self {
+            out_dir,
+            frame_count: 0,
+        }
+    }
+}
+
+impl FrameSink for PpmSequence {
+    fn frame(&mut self, frame: &Frame) {
+        let path = self
+            .out_dir
+            .join(format!("frame_{:06}.ppm", self.frame_count));
+        let mut file = BufWriter::new(
+            File::create(&path)
+                .unwrap_or_else(|_| panic!("could not create frame file: {}", path.display())),
+        );
+
+        let rgb = frame
+            .scaled_palette_indices()
+            .iter()
+            .flat_map(|i| PALETTE[*i as usize].1)
+            .collect_vec();
+
+        write!(
+            file,
+            "P6\n{} {}\n255\n",
+            frame.width * PIXELS_PER_CELL,
+            frame.height * PIXELS_PER_CELL
+        )
+        .and_then(|_| file.write_all(&rgb))
+        .unwrap_or_else(|_| panic!("could not write frame file: {}", path.display()));
+
+        self.frame_count += 1;
+    }
+}
+
+pub struct GifRecording {
+    path: PathBuf,
+    delay: Duration,
+    encoder: Option<gif::Encoder<BufWriter<File>>>,
+}
+
+impl GifRecording {
+    fn new(path: PathBuf, delay: Duration) -> Self {
+        Self {
+            path,
+            delay,
+            encoder: None,
+        }
+    }
+}
+
+impl FrameSink for GifRecording {
+    fn frame(&mut self, frame: &Frame) {
+        // the canvas size of a gif is fixed, so we take it from the first frame we get
+        let path = &self.path;
+        let encoder = self.encoder.get_or_insert_with(|| {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)
+                    .unwrap_or_else(|_| panic!("could not create frames dir: {}", dir.display()));
+            }
+
+            let file = File::create(path)
+                .unwrap_or_else(|_| panic!("could not create gif file: {}", path.display()));
+            let global_palette = PALETTE.iter().flat_map(|(_, rgb)| *rgb).collect_vec();
+
+            let mut encoder = gif::Encoder::new(
+                BufWriter::new(file),
+                (frame.width * PIXELS_PER_CELL) as u16,
+                (frame.height * PIXELS_PER_CELL) as u16,
+                &global_palette,
+            )
+            .expect("could not initialize gif encoder");
+            encoder.set_repeat(gif::Repeat::Infinite).unwrap();
+
+            encoder
+        });
+
+        let mut gif_frame = gif::Frame::from_indexed_pixels(
+            (frame.width * PIXELS_PER_CELL) as u16,
+            (frame.height * PIXELS_PER_CELL) as u16,
+            frame.scaled_palette_indices(),
+            None,
+        );
+        // gif delays are measured in units of 10ms
+        gif_frame.delay = (self.delay.as_millis() / 10) as u16;
+
+        encoder
+            .write_frame(&gif_frame)
+            .unwrap_or_else(|_| panic!("could not write gif frame: {}", path.display()));
+    }
+
+    fn finish(&mut self) {
+        // dropping the encoder writes the gif trailer
+        self.encoder.take();
+    }
+}
+
+struct InstalledSink {
+    sink: Box<dyn FrameSink>,
+    keep_every: usize,
+    emitted: usize,
+}
+
+thread_local! {
+    static SINK: RefCell<Option<InstalledSink>> = const { RefCell::new(None) };
+}
+
+/// Routes every frame emitted on the current thread to the given sink, keeping only every nth frame.
+pub fn install_sink(sink: Box<dyn FrameSink>, keep_every: usize) {
+    SINK.with(|installed| {
+        installed.replace(Some(InstalledSink {
+            sink,
+            keep_every: keep_every.max(1),
+            emitted: 0,
+        }))
+    });
+}
+
+/// Detaches the sink of the current thread, letting it flush whatever it holds.
+pub fn finish() {
+    if let Some(mut installed) = SINK.with(|installed| installed.take()) {
+        installed.sink.finish();
+    }
+}
+
+/// Emits a frame to the sink of the current thread.
+///
+/// Frames are only drawn when a sink is installed, so simulations can call this freely.
+pub fn emit(draw: impl FnOnce() -> Frame) {
+    SINK.with(|installed| {
+        if let Some(installed) = installed.borrow_mut().as_mut() {
+            if installed.emitted % installed.keep_every == 0 {
+                installed.sink.frame(&draw());
+            }
+            installed.emitted += 1;
+        }
+    });
+}